@@ -0,0 +1,150 @@
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files and directories in `dir` named `PREFIXn` or `PREFIXn.rs`,
+/// with their numbers, in order.
+fn numbered_entries(dir: &Path, prefix: &str) -> Vec<(u32, PathBuf)> {
+    let entries = fs::read_dir(dir).into_iter().flatten();
+    let mut modules: Vec<_> = entries
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name()?.to_str()?;
+            let name = name.strip_suffix(".rs").unwrap_or(name);
+            Some((name.strip_prefix(prefix)?.parse().ok()?, path))
+        })
+        .collect();
+    modules.sort();
+    modules
+}
+
+/// The numbered subdirectories of `dir`, such as years or days.
+fn numbered_dirs(dir: &Path) -> Vec<(u32, PathBuf)> {
+    let entries = numbered_entries(dir, "").into_iter();
+    entries.filter(|(_, path)| path.is_dir()).collect()
+}
+
+/// Generates the `make_runner!` invocation that registers every day module
+/// `src/yearYYYY/dayN.rs`.
+fn solvers(src_dir: &Path) -> String {
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+    let mut solvers = String::from("crate::utils::make_runner!(\n");
+    for (year, year_dir) in numbered_entries(src_dir, "year") {
+        if !year_dir.is_dir() {
+            continue;
+        }
+        // The generated file is included from OUT_DIR, so the modules' path
+        // has to be given explicitly.
+        writeln!(
+            solvers,
+            "    #[path = {:?}]",
+            year_dir.display().to_string()
+        )
+        .unwrap();
+        writeln!(solvers, "    {year}: [").unwrap();
+        for (day, _) in numbered_entries(&year_dir, "day") {
+            writeln!(solvers, "        {day},").unwrap();
+        }
+        writeln!(solvers, "    ],").unwrap();
+    }
+    solvers + ");\n"
+}
+
+/// Reads the `name=value` lines of a sample's `NAME.params` sidecar, if it
+/// has one.
+fn sample_params(path: &Path) -> Vec<(String, i64)> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let lines = contents.lines().enumerate();
+    let lines = lines.filter(|(_, line)| !line.trim().is_empty());
+    lines
+        .map(|(i, line)| {
+            let param = line.split_once('=').and_then(|(name, value)| {
+                Some((name.trim().to_string(), value.trim().parse().ok()?))
+            });
+            param.unwrap_or_else(|| panic!("{}:{}: expected `name=value`", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// Generates a test for each part of each sample under `inputs/YEAR/samples`.
+/// A sample `inputs/YEAR/samples/N/NAME.txt` is checked against the sidecar
+/// `NAME.answers`, which holds `part answer` lines, with the parameters in
+/// `NAME.params` if there is one.
+fn samples(inputs_dir: &Path) -> String {
+    let mut samples = Vec::new();
+    for (year, year_dir) in numbered_dirs(inputs_dir) {
+        let samples_dir = year_dir.join("samples");
+        println!("cargo:rerun-if-changed={}", samples_dir.display());
+        for (day, day_dir) in numbered_dirs(&samples_dir) {
+            for file in fs::read_dir(&day_dir).unwrap() {
+                let path = file.unwrap().path();
+                if path.extension().is_some_and(|ext| ext == "txt") {
+                    samples.push((year, day, path));
+                }
+            }
+        }
+    }
+    samples.sort();
+
+    let mut tests = String::new();
+    for (year, day, path) in samples {
+        let name = path.file_stem().unwrap().to_string_lossy();
+        let ident: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let answers_path = path.with_extension("answers");
+        let Ok(answers) = fs::read_to_string(&answers_path) else {
+            continue;
+        };
+        let params: String = sample_params(&path.with_extension("params"))
+            .iter()
+            .map(|(name, value)| format!("({name:?}, {value}), "))
+            .collect();
+        for (i, line) in answers.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((part @ ("1" | "2"), answer)) = line.split_once(' ') else {
+                panic!(
+                    "{}:{}: expected `part answer`",
+                    answers_path.display(),
+                    i + 1
+                );
+            };
+            writeln!(
+                tests,
+                "#[test]\n\
+                 fn year{year}_day{day}_{ident}_part{part}() {{\n    \
+                     check::<crate::year{year}::day{day}::Day{day}>(include_str!({path:?}), &[{params}], {part}, {answer:?});\n\
+                 }}\n",
+                answer = answer.trim(),
+            )
+            .unwrap();
+        }
+    }
+    tests
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = Path::new(&manifest_dir);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    let solvers = solvers(&manifest_dir.join("src"));
+    fs::write(out_dir.join("solvers.rs"), solvers).unwrap();
+    let samples = samples(&manifest_dir.join("inputs"));
+    fs::write(out_dir.join("samples.rs"), samples).unwrap();
+}
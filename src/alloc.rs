@@ -0,0 +1,144 @@
+#[cfg(feature = "count-allocs")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use adventofcode::{animate, render};
+
+/// Whether allocations are being counted, i.e. the `count-allocs` feature
+/// is enabled.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: Counter = Counter;
+
+// The counts are kept for each thread, so that only the solver being
+// measured is counted, and not, say, one that timed out and kept running
+// after it was cancelled.
+thread_local! {
+    /// Whether `measure` is counting this thread's allocations.
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    /// The bytes allocated less those freed, which goes below zero when
+    /// memory from before counting started is freed.
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// Wraps the system allocator, counting allocations and live bytes.
+#[cfg(feature = "count-allocs")]
+struct Counter;
+
+#[cfg(feature = "count-allocs")]
+impl Counter {
+    fn record(&self, grown: usize) {
+        if COUNTING.get() {
+            ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+            BYTES.set(BYTES.get() + grown as u64);
+            LIVE.set(LIVE.get() + grown as i64);
+            PEAK.set(PEAK.get().max(LIVE.get()));
+        }
+    }
+
+    fn free(&self, shrunk: usize) {
+        if COUNTING.get() {
+            LIVE.set(LIVE.get() - shrunk as i64);
+        }
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.free(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Count a reallocation as freeing the old block and allocating
+            // the new one.
+            self.free(layout.size());
+            self.record(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The allocations made while running a solver.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes that were live at once, beyond those already live
+    /// when the solver started.
+    pub peak: u64,
+}
+
+/// Runs `f`, counting the allocations it makes on this thread if the
+/// `count-allocs` feature is enabled. Nothing is counted while rendering or
+/// animating, since the images for them are built on the same thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    if !ENABLED || render::enabled() || animate::enabled() {
+        return (f(), None);
+    }
+    ALLOCATIONS.set(0);
+    BYTES.set(0);
+    LIVE.set(0);
+    PEAK.set(0);
+    let counting = COUNTING.replace(true);
+    let result = f();
+    COUNTING.set(counting);
+    let stats = Stats {
+        allocations: ALLOCATIONS.get(),
+        bytes: BYTES.get(),
+        peak: PEAK.get() as u64,
+    };
+    (result, Some(stats))
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+}
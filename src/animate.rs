@@ -0,0 +1,133 @@
+//! Live views of simulations, which solvers feed every step of while a
+//! viewer is watching, such as the runner's terminal animation.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, PoisonError, TryLockError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::render::Image;
+
+/// Shows each step of the simulations that solvers run.
+pub trait Viewer: Send {
+    /// Shows the next step of the simulation `name` from `target`, a
+    /// module path like `year2024::day15`, taking as long as it likes
+    /// while [`enabled`] stays true. Returns false to stop watching.
+    fn step(&mut self, target: &str, name: &str, image: &Image) -> bool;
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static VIEWER: Mutex<Option<Box<dyn Viewer>>> = Mutex::new(None);
+
+/// How long the viewer has spent showing steps, and when it started on the
+/// one it is showing now, if any.
+static WATCHED: Mutex<(Duration, Option<Instant>)> = Mutex::new((Duration::ZERO, None));
+
+/// How long `finish` waits for the viewer to finish showing a step.
+const FINISH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Starts feeding every step to `viewer`.
+pub fn init(viewer: Box<dyn Viewer>) {
+    *VIEWER.lock().unwrap_or_else(PoisonError::into_inner) = Some(viewer);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops watching, dropping the viewer once it has finished showing the
+/// step it is on. If it is still showing it after a second, gives up and
+/// leaves it be.
+pub fn finish() {
+    ENABLED.store(false, Ordering::Relaxed);
+    let deadline = Instant::now() + FINISH_TIMEOUT;
+    loop {
+        match VIEWER.try_lock() {
+            Ok(mut viewer) => return drop(viewer.take()),
+            Err(TryLockError::Poisoned(e)) => return drop(e.into_inner().take()),
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(TryLockError::WouldBlock) => return,
+        }
+    }
+}
+
+/// Whether a viewer is watching.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// How long the viewer has spent showing steps so far, so that timings can
+/// leave it out.
+pub fn watched() -> Duration {
+    let (total, since) = *WATCHED.lock().unwrap_or_else(PoisonError::into_inner);
+    total + since.map_or(Duration::ZERO, |since| since.elapsed())
+}
+
+/// Passes a step to the viewer, which the `step!` macro only does while
+/// one is watching.
+pub fn show(target: &str, name: &str, image: &Image) {
+    let target = target.strip_prefix("adventofcode::").unwrap_or(target);
+    let mut viewer = VIEWER.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(viewer) = viewer.as_mut() else {
+        return;
+    };
+    let start = Instant::now();
+    WATCHED.lock().unwrap_or_else(PoisonError::into_inner).1 = Some(start);
+    let watching = viewer.step(target, name, image);
+    let mut watched = WATCHED.lock().unwrap_or_else(PoisonError::into_inner);
+    *watched = (watched.0 + start.elapsed(), None);
+    if !watching {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Shows a step of a simulation, only building the `Image` while a viewer
+/// is watching.
+macro_rules! step {
+    ($name:expr, $image:expr) => {
+        if crate::animate::enabled() {
+            crate::animate::show(module_path!(), $name, &$image);
+        }
+    };
+}
+
+pub(crate) use step;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Watches a few steps of the test's simulation and then stops,
+    /// ignoring any from solvers that other tests run meanwhile.
+    struct Counter(usize);
+
+    impl Viewer for Counter {
+        fn step(&mut self, target: &str, name: &str, image: &Image) -> bool {
+            if target != "animate::test" {
+                return true;
+            }
+            assert_eq!((name, image.width), ("count", 1));
+            self.0 += 1;
+            self.0 < 3
+        }
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut built = 0;
+        let mut image = || {
+            built += 1;
+            Image::from_rows(&["#"], &[('#', [0, 0, 0])])
+        };
+        init(Box::new(Counter(0)));
+        for _ in 0..5 {
+            step!("count", image());
+        }
+        finish();
+        assert_eq!(built, 3);
+    }
+}
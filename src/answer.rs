@@ -0,0 +1,142 @@
+use std::{convert::Infallible, fmt};
+
+/// The answer to a part of a puzzle, in a form that the runner can format
+/// and check alike for every day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// A position, written `x,y`.
+    Coord(i64, i64),
+    /// A picture of rows of characters, along with the answer that it
+    /// shows, such as the letters it spells or the time when it appears.
+    Grid(Box<Answer>, Vec<String>),
+}
+
+impl Answer {
+    /// The picture of a grid answer, as rows of characters.
+    pub fn picture(&self) -> Option<&[String]> {
+        match self {
+            Answer::Grid(_, rows) => Some(rows),
+            _ => None,
+        }
+    }
+
+    /// Whether the answer is `expected`, as written in an answers file. Numbers
+    /// and positions are compared by value, so that `+7` matches `7` and
+    /// `1, 2` matches `1,2`.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Answer::Int(n) => expected.parse() == Ok(*n),
+            Answer::Text(text) => text.trim() == expected,
+            Answer::Coord(x, y) => match expected.split_once(',') {
+                Some((ex, ey)) => (ex.trim().parse(), ey.trim().parse()) == (Ok(*x), Ok(*y)),
+                None => false,
+            },
+            Answer::Grid(answer, _) => answer.matches(expected),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Coord(x, y) => write!(f, "{x},{y}"),
+            Answer::Grid(answer, _) => write!(f, "{answer}"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n.into())
+            }
+        })*
+    };
+}
+
+from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<(i32, i32)> for Answer {
+    fn from((x, y): (i32, i32)) -> Self {
+        Answer::Coord(x.into(), y.into())
+    }
+}
+
+impl From<(i64, i64)> for Answer {
+    fn from((x, y): (i64, i64)) -> Self {
+        Answer::Coord(x, y)
+    }
+}
+
+/// For a part that has no answer, such as the second part of the last day.
+impl From<Infallible> for Answer {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+/// What a part returns: an answer, or a `Result` whose error says why the
+/// input has none, such as a maze whose exit can't be reached.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, String>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, String> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, String> {
+    fn into_answer(self) -> Result<Answer, String> {
+        self.map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert!(Answer::from(-7i64).matches("-7"));
+        assert!(Answer::from(7usize).matches("+7"));
+        assert!(!Answer::from(7usize).matches("7a"));
+        assert_eq!(Answer::from((6, 1)).to_string(), "6,1");
+        assert!(Answer::from((6, 1)).matches("6, 1"));
+        assert!(!Answer::from((6, 1)).matches("1,6"));
+        assert!(Answer::from("co,de,ka,ta").matches("co,de,ka,ta\n"));
+        let grid = Answer::Grid(Box::new(Answer::from(7)), vec!["#.".into(), ".#".into()]);
+        assert_eq!(grid.to_string(), "7");
+        assert!(grid.matches("7"));
+        assert_eq!(grid.picture().map(<[String]>::len), Some(2));
+        assert_eq!(7usize.into_answer(), Ok(Answer::Int(7)));
+        let none: Result<usize, String> = Err("no path".into());
+        assert_eq!(none.into_answer(), Err("no path".into()));
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use adventofcode::{Answer, Solver};
+
+use crate::input::InputArgs;
+
+#[derive(clap::Args, Debug)]
+pub struct AnswerArgs {
+    /// Check results against FILE, which holds `day part answer` lines
+    /// [default: answers.txt in each year's inputs directory]
+    #[arg(long, value_name = "FILE")]
+    answers: Option<PathBuf>,
+
+    /// Add results that are not in the answers file yet to it
+    #[arg(long, conflicts_with = "sample")]
+    record: bool,
+}
+
+pub enum Check {
+    Pass,
+    Fail(String),
+    New,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "PASS"),
+            Check::Fail(_) => write!(f, "FAIL"),
+            Check::New => write!(f, "NEW"),
+        }
+    }
+}
+
+/// Reads a sample's answers file, which holds `part answer` lines. A
+/// missing file has no answers.
+pub fn sample_answers(path: &Path) -> Result<Vec<(u32, String)>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+    let mut answers = Vec::new();
+    let lines = contents.lines().enumerate();
+    for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        let Some((Ok(part), answer)) = line
+            .trim()
+            .split_once(' ')
+            .map(|(part, answer)| (part.parse(), answer))
+        else {
+            let path = path.display();
+            return Err(format!("{path}:{}: expected `part answer`", i + 1));
+        };
+        answers.push((part, answer.trim().to_string()));
+    }
+    Ok(answers)
+}
+
+/// The expected answers for each year, day and part.
+pub struct Answers {
+    /// Whether there is anything to check answers against.
+    checked: bool,
+    /// The answers file for each year that has one.
+    paths: BTreeMap<u32, PathBuf>,
+    record: bool,
+    answers: BTreeMap<(u32, u32, u32), String>,
+    /// The years that answers were recorded for.
+    recorded: BTreeSet<u32>,
+}
+
+/// Reads an answers file, which holds `day part answer` lines. A missing
+/// file has no answers.
+fn read_answers(path: &Path) -> Result<Vec<(u32, u32, String)>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+    let mut answers = Vec::new();
+    let lines = contents.lines().enumerate();
+    for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        let mut fields = line.trim().splitn(3, ' ');
+        let (Some(Ok(day)), Some(Ok(part)), Some(answer)) = (
+            fields.next().map(str::parse),
+            fields.next().map(str::parse),
+            fields.next(),
+        ) else {
+            let path = path.display();
+            return Err(format!("{path}:{}: expected `day part answer`", i + 1));
+        };
+        answers.push((day, part, answer.trim().to_string()));
+    }
+    Ok(answers)
+}
+
+impl Answers {
+    /// Loads the answers file for each of `years`. Without an explicit
+    /// `--answers`, answers are only checked when the inputs come from an
+    /// inputs directory, as they are unlikely to match a one-off `--input`.
+    /// An explicit answers file only holds the answers for a single year.
+    pub fn load(args: &AnswerArgs, input: &InputArgs, years: &[u32]) -> Result<Self, String> {
+        if let (None, Some(name)) = (&args.answers, input.sample()) {
+            return Self::load_sample(input, years, name);
+        }
+        let paths: BTreeMap<_, _> = match (&args.answers, years) {
+            (Some(path), &[year]) => [(year, path.clone())].into(),
+            (Some(_), _) => return Err("--answers can only be used with a single year".into()),
+            (None, _) => years
+                .iter()
+                .filter_map(|&year| Some((year, input.answers_path(year)?)))
+                .collect(),
+        };
+        let mut answers = BTreeMap::new();
+        for (&year, path) in &paths {
+            for (day, part, answer) in read_answers(path)? {
+                answers.insert((year, day, part), answer);
+            }
+        }
+        Ok(Self {
+            checked: !paths.is_empty(),
+            paths,
+            record: args.record,
+            answers,
+            recorded: BTreeSet::new(),
+        })
+    }
+
+    /// Loads the answers for the sample `name` of every day of `years`,
+    /// from the `N/name.answers` files in each year's samples directory.
+    fn load_sample(input: &InputArgs, years: &[u32], name: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for &year in years {
+            let dir = input.samples_dir(year);
+            for entry in fs::read_dir(&dir).into_iter().flatten() {
+                let entry = entry.map_err(|e| format!("Failed to read {}: {e}", dir.display()))?;
+                let Some(day) = entry.file_name().to_str().and_then(|n| n.parse().ok()) else {
+                    continue;
+                };
+                let path = entry.path().join(format!("{name}.answers"));
+                for (part, answer) in sample_answers(&path)? {
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+        Ok(Self {
+            checked: true,
+            paths: BTreeMap::new(),
+            record: false,
+            answers,
+            recorded: BTreeSet::new(),
+        })
+    }
+
+    /// Compares `answer` with the expected answer, recording it if it is
+    /// new and `--record` was given. Returns `None` if there is no answers
+    /// file to check against.
+    pub fn check(&mut self, solver: &Solver, answer: &Answer) -> Option<Check> {
+        if !self.checked {
+            return None;
+        }
+        let key = (solver.year, solver.day, solver.part);
+        Some(match self.answers.get(&key) {
+            Some(expected) if answer.matches(expected) => Check::Pass,
+            Some(expected) => Check::Fail(expected.clone()),
+            None => {
+                if self.record {
+                    self.answers.insert(key, answer.to_string());
+                    self.recorded.insert(solver.year);
+                }
+                Check::New
+            }
+        })
+    }
+
+    /// Writes the answers file for each year back out if any of its
+    /// answers were recorded.
+    pub fn save(&self) -> Result<(), String> {
+        for year in &self.recorded {
+            let path = &self.paths[year];
+            let contents: String = self
+                .answers
+                .range((*year, 0, 0)..=(*year, u32::MAX, u32::MAX))
+                .map(|((_, day, part), answer)| format!("{day} {part} {answer}\n"))
+                .collect();
+            fs::write(path, contents)
+                .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+        }
+        Ok(())
+    }
+}
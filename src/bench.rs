@@ -0,0 +1,188 @@
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use adventofcode::Solver;
+use itertools::Itertools;
+
+use crate::{
+    history::{self, HistoryArgs},
+    input::InputArgs,
+    runner::timed,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct BenchArgs {
+    /// Seconds to spend running each solver before timing it
+    #[arg(long, value_name = "SECS", default_value_t = 0.5)]
+    warmup: f64,
+
+    /// Seconds to spend timing each solver; every solver runs at least once
+    #[arg(long, value_name = "SECS", default_value_t = 3.0)]
+    budget: f64,
+
+    /// Column to sort the table by; timings sort slowest first
+    #[arg(long, value_enum, default_value_t = SortKey::Day)]
+    sort: SortKey,
+}
+
+#[derive(clap::ValueEnum, Copy, Clone, Debug)]
+enum SortKey {
+    Day,
+    Iters,
+    Min,
+    Median,
+    Mean,
+    P95,
+}
+
+struct Stats {
+    iters: usize,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    p95: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        Self {
+            iters: n,
+            min: samples[0],
+            median: (samples[(n - 1) / 2] + samples[n / 2]) / 2,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+            p95: samples[(n * 95).div_ceil(100) - 1],
+        }
+    }
+}
+
+/// Runs `f` repeatedly until `budget` has passed, returning the time taken
+/// by each run, or `None` if it panicked.
+fn sample(mut f: impl FnMut(), budget: Duration) -> Option<Vec<Duration>> {
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    while samples.is_empty() || start.elapsed() < budget {
+        let (result, duration) = timed(&mut f);
+        result.ok()?;
+        samples.push(duration);
+    }
+    Some(samples)
+}
+
+/// Warms `f` up and then samples it.
+fn bench_fn(mut f: impl FnMut(), warmup: Duration, budget: Duration) -> Option<Stats> {
+    sample(&mut f, warmup)?;
+    sample(f, budget).map(Stats::new)
+}
+
+/// Benchmarks each solver, printing a table of the results and appending
+/// them to the history file.
+pub fn bench(
+    solvers: &[&Solver],
+    input: &InputArgs,
+    args: &BenchArgs,
+    history: &HistoryArgs,
+) -> Result<(), String> {
+    let warmup = Duration::from_secs_f64(args.warmup);
+    let budget = Duration::from_secs_f64(args.budget);
+    let mut rows = Vec::new();
+    let mut input_hashes = HashMap::new();
+    let mut failed = Vec::new();
+    for ((year, day), day_solvers) in &solvers.iter().group_by(|solver| (solver.year, solver.day)) {
+        let day_solvers = day_solvers.collect_vec();
+        let parse = day_solvers[0].parse;
+        let loaded = input.load(year, day, day_solvers[0].embedded);
+        let (day_input, params) = match loaded.and_then(|i| Ok((i, input.params(day_solvers[0])?)))
+        {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                failed.push(e);
+                continue;
+            }
+        };
+        input_hashes.insert((year, day), history::input_hash(&day_input));
+        // Only time parsing an input that parses, so that a failure doesn't
+        // show up as a result.
+        let parsed = match timed(|| parse(&day_input, &params)).0 {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(e)) => {
+                failed.push(format!(
+                    "Failed to parse the input for {year} day {day} at {e}"
+                ));
+                continue;
+            }
+            Err(_) => {
+                failed.push(format!("{year} day {day} panicked while parsing"));
+                continue;
+            }
+        };
+        match bench_fn(
+            || drop(black_box(parse(&day_input, &params))),
+            warmup,
+            budget,
+        ) {
+            Some(stats) => rows.push(((year, day), "parse".to_string(), stats)),
+            None => {
+                failed.push(format!("{year} day {day} panicked while parsing"));
+                continue;
+            }
+        }
+        for solver in day_solvers {
+            let solve = || drop(black_box(parsed.solve(solver.part)));
+            match bench_fn(solve, warmup, budget) {
+                Some(stats) => rows.push(((year, day), solver.part.to_string(), stats)),
+                None => failed.push(format!("{} panicked", solver.label())),
+            }
+        }
+    }
+    match args.sort {
+        SortKey::Day => (),
+        SortKey::Iters => rows.sort_by_key(|(_, _, stats)| stats.iters),
+        SortKey::Min => rows.sort_by_key(|(_, _, stats)| std::cmp::Reverse(stats.min)),
+        SortKey::Median => rows.sort_by_key(|(_, _, stats)| std::cmp::Reverse(stats.median)),
+        SortKey::Mean => rows.sort_by_key(|(_, _, stats)| std::cmp::Reverse(stats.mean)),
+        SortKey::P95 => rows.sort_by_key(|(_, _, stats)| std::cmp::Reverse(stats.p95)),
+    }
+    println!(
+        "{:>4}  {:>3}  {:>5}  {:>8}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Year", "Day", "Part", "Iters", "Min (ms)", "Median", "Mean", "P95"
+    );
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    for ((year, day), part, stats) in &rows {
+        println!(
+            "{:>4}  {:>3}  {:>5}  {:>8}  {:>10.3}  {:>10.3}  {:>10.3}  {:>10.3}",
+            year,
+            day,
+            part,
+            stats.iters,
+            ms(stats.min),
+            ms(stats.median),
+            ms(stats.mean),
+            ms(stats.p95),
+        );
+    }
+    let (timestamp, rev) = (history::now(), history::git_rev());
+    let entries = rows
+        .into_iter()
+        .map(|((year, day), part, stats)| history::Entry {
+            timestamp,
+            rev: rev.clone(),
+            year,
+            day,
+            part,
+            input_hash: input_hashes[&(year, day)].clone(),
+            median: stats.median,
+            min: stats.min,
+            iters: stats.iters,
+        })
+        .collect_vec();
+    history::append(history, &entries)?;
+    match failed.len() {
+        0 => Ok(()),
+        _ => Err(failed.join("\n")),
+    }
+}
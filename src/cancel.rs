@@ -0,0 +1,67 @@
+//! Cooperative cancellation of solvers, so that the runner can stop one
+//! that it has given up waiting for. Solvers call [`check`] in their
+//! long-running loops, which unwinds out of the solver once the runner has
+//! cancelled it, and otherwise costs next to nothing.
+
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// The payload that [`check`] unwinds with, which isn't a real panic and
+/// so isn't reported by the panic hook.
+pub struct Cancelled;
+
+thread_local! {
+    static FLAG: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Cancels whatever this thread is running once `flag` is set.
+pub fn install(flag: Arc<AtomicBool>) {
+    FLAG.set(Some(flag));
+}
+
+/// Whether what this thread is running has been cancelled.
+pub fn cancelled() -> bool {
+    FLAG.with_borrow(|flag| {
+        flag.as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    })
+}
+
+/// Unwinds with [`Cancelled`] if what this thread is running has been
+/// cancelled.
+pub fn check() {
+    if cancelled() {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{panic::catch_unwind, thread};
+
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let worker_flag = flag.clone();
+        let result = thread::spawn(move || {
+            install(worker_flag);
+            catch_unwind(|| loop {
+                check();
+            })
+        });
+        // Not cancelled on other threads.
+        check();
+        flag.store(true, Ordering::Relaxed);
+        check();
+        let payload = result.join().unwrap().unwrap_err();
+        assert!(payload.is::<Cancelled>());
+    }
+}
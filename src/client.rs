@@ -0,0 +1,216 @@
+use std::{env, fs, io, path::PathBuf};
+
+#[derive(clap::Args, Debug)]
+pub struct ClientArgs {
+    /// The Advent of Code server to talk to
+    #[arg(long, value_name = "URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+
+    /// Read the session token from FILE when AOC_SESSION isn't set
+    /// [default: ~/.config/adventofcode/session]
+    #[arg(long, value_name = "FILE")]
+    session_file: Option<PathBuf>,
+}
+
+/// The environment variable holding the session token.
+const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Talks to the Advent of Code server on behalf of a logged-in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl ClientArgs {
+    fn session_file(&self) -> Option<PathBuf> {
+        let config_dir = || match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) => Some(PathBuf::from(dir)),
+            None => Some(PathBuf::from(env::var_os("HOME")?).join(".config")),
+        };
+        (self.session_file.clone()).or_else(|| Some(config_dir()?.join("adventofcode/session")))
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client with the session token from `AOC_SESSION`, or else
+    /// from the session file.
+    pub fn from_args(args: &ClientArgs) -> Result<Self, String> {
+        if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()) {
+            return Ok(Self::new(&args.base_url, &session));
+        }
+        let Some(path) = args.session_file() else {
+            return Err(format!(
+                "No session token: set {SESSION_VAR} or pass --session-file"
+            ));
+        };
+        match fs::read_to_string(&path) {
+            Ok(session) => Ok(Self::new(&args.base_url, &session)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(format!(
+                "No session token: set {SESSION_VAR} or save it to {}",
+                path.display()
+            )),
+            Err(e) => Err(format!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str) -> (String, ureq::Request) {
+        let url = format!("{}{path}", self.base_url);
+        let request = ureq::request(method, &url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT);
+        (url, request)
+    }
+
+    /// Reads the body of a response, turning the errors that any request
+    /// can run into into messages. `not_found` describes a 404.
+    fn body(
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+        not_found: impl FnOnce() -> String,
+    ) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("Failed to read the response from {url}: {e}")),
+            Err(ureq::Error::Status(404, _)) => Err(not_found()),
+            Err(ureq::Error::Status(429, response)) => Err(match response.header("Retry-After") {
+                Some(secs) => format!("Rate limited by the server; try again in {secs} seconds"),
+                None => "Rate limited by the server; wait a while before trying again".into(),
+            }),
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(format!(
+                "The server rejected the session token; log in again and update {SESSION_VAR} \
+                 or the session file"
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(format!("Unexpected HTTP status {status} from {url}"))
+            }
+            // The error already names the URL.
+            Err(ureq::Error::Transport(e)) => Err(format!("Failed to request {e}")),
+        }
+    }
+
+    /// Downloads the puzzle input for `day` of `year`.
+    pub fn input(&self, year: u32, day: u32) -> Result<String, String> {
+        let (url, request) = self.request("GET", &format!("/{year}/day/{day}/input"));
+        Self::body(&url, request.call(), || {
+            format!("The puzzle for {year} day {day} isn't unlocked yet")
+        })
+    }
+
+    /// Submits `answer` for `part` of `day` of `year`, returning the page
+    /// that the server responds with.
+    pub fn submit(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<String, String> {
+        let (url, request) = self.request("POST", &format!("/{year}/day/{day}/answer"));
+        let part = part.to_string();
+        let form = [("level", part.as_str()), ("answer", answer)];
+        Self::body(&url, request.send_form(&form), || {
+            format!("The puzzle for {year} day {day} isn't unlocked yet")
+        })
+    }
+}
+
+/// A stand-in for the Advent of Code server, for testing the client.
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Formats an HTTP response. `headers` are `Name: value` lines, each
+    /// ending in `\r\n`.
+    pub fn response(status: &str, headers: &str, body: &str) -> String {
+        let length = body.len();
+        format!("HTTP/1.1 {status}\r\n{headers}Content-Length: {length}\r\nConnection: close\r\n\r\n{body}")
+    }
+
+    /// Answers each connection with the next of its responses, and records
+    /// each request's head and body.
+    pub struct Server {
+        pub url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Server {
+        pub fn new(responses: Vec<String>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for response in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request += &line;
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut body = vec![0; length];
+                    reader.read_exact(&mut body).unwrap();
+                    request += &String::from_utf8(body).unwrap();
+                    recorded.lock().unwrap().push(request);
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{stub::*, *};
+
+    #[test]
+    fn test_input() {
+        let server = Server::new(vec![
+            response("200 OK", "", "1 2\n"),
+            response("404 Not Found", "", ""),
+            response("429 Too Many Requests", "Retry-After: 60\r\n", ""),
+            response("400 Bad Request", "", ""),
+        ]);
+        let client = Client::new(&server.url, "abc\n");
+        assert_eq!(client.input(2024, 1).as_deref(), Ok("1 2\n"));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert_eq!(
+            client.input(2024, 2),
+            Err("The puzzle for 2024 day 2 isn't unlocked yet".to_string())
+        );
+        assert_eq!(
+            client.input(2024, 2),
+            Err("Rate limited by the server; try again in 60 seconds".to_string())
+        );
+        assert!(client
+            .input(2024, 2)
+            .unwrap_err()
+            .contains("rejected the session token"));
+    }
+}
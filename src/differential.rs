@@ -0,0 +1,135 @@
+//! Differential tests, which check each solution that has a reference
+//! solution against it on generated inputs, shrinking any input they
+//! disagree on to a small counterexample.
+
+use std::{
+    iter::successors,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use itertools::Itertools;
+
+use crate::{Answer, Params, Solver};
+
+/// How many inputs to generate for each solution.
+const SEEDS: u64 = 100;
+
+/// The largest size to generate inputs at, small enough for the references.
+const MAX_SIZE: u64 = 16;
+
+/// The answers of `solver` and of its reference to `input`, or `None` if
+/// either of them fails.
+fn answers(solver: &Solver, input: &str, params: &Params) -> Option<(Answer, Answer)> {
+    let answers = catch_unwind(AssertUnwindSafe(|| {
+        let parsed = (solver.parse)(input, params).ok()?;
+        Some((
+            parsed.solve(solver.part).ok()?,
+            parsed.reference(solver.part)?.ok()?,
+        ))
+    }));
+    answers.ok().flatten()
+}
+
+fn join<'a>(lines: impl IntoIterator<Item = &'a str>) -> String {
+    lines.into_iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Shrinks `input` for as long as some smaller input still `fails`, trying
+/// to remove runs of lines, from long runs to single lines, then columns and
+/// then single characters.
+fn shrink(mut input: String, mut fails: impl FnMut(&str) -> bool) -> String {
+    loop {
+        let lines = &input.lines().collect_vec();
+        let runs = successors(Some(lines.len()), |&len| (len > 1).then_some(len / 2));
+        let without_lines = runs.flat_map(|len| {
+            (0..lines.len()).step_by(len).map(move |start| {
+                let kept = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i < start || i >= start + len);
+                join(kept.map(|(_, line)| *line))
+            })
+        });
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let without_columns = (0..width).map(|column| {
+            let lines = lines.iter().map(|line| {
+                let kept = line.chars().enumerate().filter(|&(i, _)| i != column);
+                kept.map(|(_, c)| c).collect::<String>()
+            });
+            lines.map(|line| line + "\n").collect::<String>()
+        });
+        let without_chars = input
+            .char_indices()
+            .filter(|&(_, c)| c != '\n')
+            .map(|(i, c)| format!("{}{}", &input[..i], &input[i + c.len_utf8()..]));
+        let smaller = without_lines
+            .chain(without_columns)
+            .chain(without_chars)
+            .filter(|candidate| candidate.len() < input.len())
+            .find(|candidate| fails(candidate));
+        match smaller {
+            Some(smaller) => input = smaller,
+            None => return input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{rng::Rng, SOLVERS};
+
+    #[test]
+    fn test_shrink() {
+        let input = "abc\ndef\nxyz\nghi\n".to_string();
+        assert_eq!(shrink(input, |input| input.contains('x')), "x\n");
+        let input = "ab\nxb\nab\n".to_string();
+        let fails = |input: &str| input.lines().filter(|line| line.contains('b')).count() > 1;
+        assert_eq!(shrink(input, fails), "b\nb\n");
+    }
+
+    #[test]
+    fn test_references() {
+        for solver in SOLVERS {
+            for seed in 0..SEEDS {
+                let size = 1 + (seed % MAX_SIZE) as usize;
+                let Some(generated) = (solver.generate)(&mut Rng::new(seed), size) else {
+                    break;
+                };
+                let mut params = Params::new(solver.params);
+                for &(name, value) in &generated.params {
+                    params.set(name, value).unwrap();
+                }
+                let (answer, reference) = {
+                    let parsed = (solver.parse)(&generated.input, &params).unwrap();
+                    let Some(reference) = parsed.reference(solver.part) else {
+                        continue;
+                    };
+                    // Every generated input has an answer.
+                    let label = solver.label();
+                    let answer = parsed.solve(solver.part);
+                    (
+                        answer.unwrap_or_else(|e| panic!("{label}: {e}")),
+                        reference.unwrap_or_else(|e| panic!("{label}'s reference: {e}")),
+                    )
+                };
+                if answer == reference {
+                    continue;
+                }
+                let disagree =
+                    |input: &str| matches!(answers(solver, input, &params), Some((a, b)) if a != b);
+                let input = shrink(generated.input, disagree);
+                let (answer, reference) = answers(solver, &input, &params).unwrap();
+                panic!(
+                    "{} answered {answer} but the reference {reference} for this input, shrunk from \
+                     seed {seed} at size {size}:\n{input}",
+                    solver.label()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use nom::{
+    character::complete::multispace0,
+    combinator::eof,
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    sequence::terminated,
+    Finish, Parser,
+};
+
+/// The result of a nom parser for [`parse`], which can say what it expected
+/// with nom's `context`.
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// A puzzle input that could not be parsed, with the position (both
+/// 1-based) where parsing stopped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    /// A short description of what was expected at this position.
+    pub expected: String,
+    /// The character at this position, or `None` at the end of the input.
+    pub found: Option<char>,
+    /// The text of the line containing the error, for showing context.
+    pub source_line: String,
+}
+
+impl Error {
+    /// An error at the start of `rest`, which must be a slice of `input`.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = rest.as_ptr() as usize - input.as_ptr() as usize;
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: rest.chars().next(),
+            source_line: input[line_start..line_end].trim_end().to_string(),
+        }
+    }
+
+    /// An error at the end of `input`, for something that was never found.
+    pub fn missing(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        match self.found {
+            Some(c) => write!(f, ", found {c:?}"),
+            None => write!(f, ", found the end of the input"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn describe(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Digit | ErrorKind::MapRes => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Tag => "a keyword or separator",
+        ErrorKind::Char | ErrorKind::OneOf => "a particular character",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::Eof => "the end of the input",
+        ErrorKind::Many1 | ErrorKind::SeparatedList => "at least one item",
+        ErrorKind::ManyTill => "a match before the end of the input",
+        _ => "a valid value",
+    }
+}
+
+/// Runs a nom parser over the whole of `input`, which may only have
+/// whitespace left over. An error is reported where parsing stopped, as
+/// expecting what the innermost `context` around it says, if any.
+pub fn parse<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> Result<T, Error> {
+    terminated(parser, terminated(multispace0, eof))(input)
+        .finish()
+        .map(|(_, result)| result)
+        .map_err(|e| {
+            let (rest, kind) = &e.errors[0];
+            let context = e.errors.iter().find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            });
+            let expected = context.unwrap_or_else(|| match kind {
+                VerboseErrorKind::Char(c) => format!("{c:?}"),
+                VerboseErrorKind::Nom(kind) => describe(*kind).to_string(),
+                VerboseErrorKind::Context(context) => context.to_string(),
+            });
+            Error::at(input, rest, expected)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::digit1,
+        combinator::cut,
+        error::context,
+        multi::many1,
+        sequence::{preceded, separated_pair},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "12\n3x4\n";
+        let error = Error::at(input, &input[4..], "a digit");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.source_line, "3x4");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a digit, found 'x'"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let numbers = || many1(preceded(multispace0, digit1));
+        assert_eq!(parse("1 2\n3\n", numbers()), Ok(vec!["1", "2", "3"]));
+        let error = parse("1 2\n3x\n", numbers()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "the end of the input");
+    }
+
+    #[test]
+    fn test_context() {
+        // Without a cut, the list stops before the bad pair.
+        let pair = separated_pair(digit1, tag(","), digit1);
+        let error = parse("1,2\n3;4\n", many1(preceded(multispace0, pair))).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let pair = context(
+            "a pair like `1,2`",
+            separated_pair(digit1, cut(tag(",")), cut(digit1)),
+        );
+        let error = parse("1,2\n3;4\n", many1(preceded(multispace0, pair))).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a pair like `1,2`, found ';'"
+        );
+    }
+}
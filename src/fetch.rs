@@ -0,0 +1,86 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::client::{Client, ClientArgs};
+
+#[derive(clap::Args, Debug)]
+pub struct FetchArgs {
+    /// The day to download the input for
+    day: u32,
+
+    /// The year of the puzzle [default: the latest registered year]
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// Save the input for day N of YEAR to DIR/YEAR/N.txt
+    #[arg(long, value_name = "DIR", default_value = "inputs")]
+    inputs_dir: PathBuf,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+/// Saves the input from `download` to `path`, unless a non-empty input is
+/// already there. Returns whether it was downloaded.
+fn fetch_to(
+    path: &Path,
+    download: impl FnOnce() -> Result<String, String>,
+) -> Result<bool, String> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() > 0 => return Ok(false),
+        Ok(_) => (),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    }
+    let input = download()?;
+    let error = |e: io::Error| format!("Failed to write {}: {e}", path.display());
+    fs::create_dir_all(path.parent().unwrap()).map_err(error)?;
+    fs::write(path, input).map_err(error)?;
+    Ok(true)
+}
+
+/// Downloads the input for a day into the inputs directory. An input that
+/// is already there is never downloaded again.
+pub fn fetch(args: &FetchArgs, latest_year: Option<u32>) -> Result<(), String> {
+    let year = args
+        .year
+        .or(latest_year)
+        .ok_or("No years are registered, so a --year is needed")?;
+    let path = args.inputs_dir.join(format!("{year}/{}.txt", args.day));
+    let download = || Client::from_args(&args.client)?.input(year, args.day);
+    match fetch_to(&path, download)? {
+        true => println!(
+            "Saved the input for {year} day {} to {}",
+            args.day,
+            path.display()
+        ),
+        false => println!("Using the input already in {}", path.display()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, process};
+
+    use super::*;
+    use crate::client::stub::{response, Server};
+
+    #[test]
+    fn test_fetch_to() {
+        let server = Server::new(vec![response("200 OK", "", "1 2\n")]);
+        let client = Client::new(&server.url, "abc");
+        let dir = env::temp_dir().join(format!("adventofcode-fetch-{}", process::id()));
+        let path = dir.join("2024/1.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        // An empty input, as `new` creates, hasn't been fetched yet.
+        fs::write(&path, "").unwrap();
+        assert_eq!(fetch_to(&path, || client.input(2024, 1)), Ok(true));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n");
+        assert_eq!(fetch_to(&path, || client.input(2024, 1)), Ok(false));
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
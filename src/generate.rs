@@ -0,0 +1,107 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use adventofcode::{rng::Rng, Generated, SOLVERS};
+use itertools::Itertools;
+
+#[derive(clap::Args, Debug)]
+pub struct GenerateArgs {
+    /// The day to generate an input for
+    day: u32,
+
+    /// The year of the puzzle [default: the latest registered year]
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// How big an input to generate, such as the number of lines or the
+    /// width of a grid, depending on the day
+    #[arg(long, value_name = "N")]
+    size: usize,
+
+    /// Seed the random number generator with S; the same seed and size
+    /// always generate the same input
+    #[arg(long, value_name = "S", default_value_t = 0)]
+    seed: u64,
+
+    /// Write the input to FILE instead of stdout, and the parameters it
+    /// needs to the sidecar FILE.params, as for a sample
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+/// Generates an input for `day` of `year` with the day's generator.
+fn generate(year: u32, day: u32, size: usize, seed: u64) -> Result<Generated, String> {
+    let solver = SOLVERS
+        .iter()
+        .find(|solver| (solver.year, solver.day) == (year, day))
+        .ok_or(format!("{year} day {day} is not registered"))?;
+    (solver.generate)(&mut Rng::new(seed), size)
+        .ok_or(format!("{year} day {day} has no input generator"))
+}
+
+/// Generates a random input for a day, writing it to stdout or a file.
+pub fn run(args: &GenerateArgs, latest_year: Option<u32>) -> Result<(), String> {
+    let year = args
+        .year
+        .or(latest_year)
+        .ok_or("No years are registered, so a --year is needed")?;
+    let generated = generate(year, args.day, args.size, args.seed)?;
+    let Some(path) = &args.output else {
+        print!("{}", generated.input);
+        if !generated.params.is_empty() {
+            let params = generated.params.iter();
+            let mut params = params.map(|(name, value)| format!("--param {name}={value}"));
+            eprintln!("Solve this input with {}", params.join(" "));
+        }
+        return Ok(());
+    };
+    let error = |path: &Path, e: io::Error| format!("Failed to write {}: {e}", path.display());
+    fs::write(path, &generated.input).map_err(|e| error(path, e))?;
+    if !generated.params.is_empty() {
+        let params_path = path.with_extension("params");
+        let params = generated.params.iter();
+        let params: String = params
+            .map(|(name, value)| format!("{name}={value}\n"))
+            .collect();
+        fs::write(&params_path, params).map_err(|e| error(&params_path, e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use adventofcode::Params;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for solver in SOLVERS.iter().filter(|solver| solver.part == 1) {
+            for seed in 0..3 {
+                let Ok(generated) = generate(solver.year, solver.day, 10, seed) else {
+                    continue;
+                };
+                assert_eq!(
+                    generate(solver.year, solver.day, 10, seed),
+                    Ok(generated.clone())
+                );
+                let mut params = Params::new(solver.params);
+                for &(name, value) in &generated.params {
+                    params.set(name, value).unwrap();
+                }
+                let parsed = (solver.parse)(&generated.input, &params);
+                let parsed = parsed.unwrap_or_else(|e| panic!("{}: {e}", solver.label()));
+                let parts = SOLVERS
+                    .iter()
+                    .filter(|s| (s.year, s.day) == (solver.year, solver.day));
+                for part in parts {
+                    if let Err(e) = parsed.solve(part.part) {
+                        panic!("{}: {e}", part.label());
+                    }
+                }
+            }
+        }
+    }
+}
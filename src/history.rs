@@ -0,0 +1,333 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    ptr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct HistoryArgs {
+    /// The file that benchmark results are appended to and compared from
+    #[arg(long, value_name = "FILE", default_value = "inputs/history.csv")]
+    history: PathBuf,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CompareArgs {
+    /// Compare against the results for git revision REV [default: the latest
+    /// results from an earlier revision, for each solver]
+    #[arg(long, value_name = "REV")]
+    baseline: Option<String>,
+
+    /// Flag solvers whose median time grew by more than PCT percent
+    #[arg(long, value_name = "PCT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// A benchmark result for one day and part (or `parse`) of one input.
+pub struct Entry {
+    pub timestamp: u64,
+    pub rev: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: String,
+    pub input_hash: String,
+    pub median: Duration,
+    pub min: Duration,
+    pub iters: usize,
+}
+
+const HEADER: &str = "timestamp,rev,year,day,part,input_hash,median_ns,min_ns,iters";
+
+/// Hashes an input with 64-bit FNV-1a, so that results are only compared
+/// when they come from the same input.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325, |hash: u64, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// The git revision of the working tree, marked `-dirty` if it has
+/// uncommitted changes.
+pub fn git_rev() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_string(), |rev| rev.trim().to_string())
+}
+
+pub fn now() -> u64 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH);
+    now.map_or(0, |now| now.as_secs())
+}
+
+/// Appends `entries` to the history file, creating it if needed.
+pub fn append(args: &HistoryArgs, entries: &[Entry]) -> Result<(), String> {
+    let path = &args.history;
+    let error = |e: io::Error| format!("Failed to write {}: {e}", path.display());
+    let is_new = fs::metadata(path).map_or(true, |metadata| metadata.len() == 0);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(error)?;
+    let mut contents = String::new();
+    if is_new {
+        contents += HEADER;
+        contents.push('\n');
+    }
+    for entry in entries {
+        contents += &format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            entry.timestamp,
+            entry.rev,
+            entry.year,
+            entry.day,
+            entry.part,
+            entry.input_hash,
+            entry.median.as_nanos(),
+            entry.min.as_nanos(),
+            entry.iters,
+        );
+    }
+    file.write_all(contents.as_bytes()).map_err(error)
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let [timestamp, rev, year, day, part, input_hash, median, min, iters] = fields[..] else {
+        return None;
+    };
+    Some(Entry {
+        timestamp: timestamp.parse().ok()?,
+        rev: rev.to_string(),
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.to_string(),
+        input_hash: input_hash.to_string(),
+        median: Duration::from_nanos(median.parse().ok()?),
+        min: Duration::from_nanos(min.parse().ok()?),
+        iters: iters.parse().ok()?,
+    })
+}
+
+fn load(args: &HistoryArgs) -> Result<Vec<Entry>, String> {
+    let path = &args.history;
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let lines = contents.lines().enumerate().skip(1);
+    let mut entries = Vec::new();
+    for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        let Some(entry) = parse_entry(line.trim()) else {
+            return Err(format!("{}:{}: expected `{HEADER}`", path.display(), i + 1));
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Orders parts as `bench` prints them, with `parse` first.
+fn part_key(part: &str) -> (bool, &str) {
+    (part != "parse", part)
+}
+
+/// The latest result for one solver and input, and the result it is
+/// compared with, if there is one.
+struct Comparison<'a> {
+    current: &'a Entry,
+    baseline: Option<&'a Entry>,
+}
+
+impl Comparison<'_> {
+    /// How much the median time grew since the baseline, in percent.
+    fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.median.as_secs_f64();
+        Some((self.current.median.as_secs_f64() / baseline - 1.0) * 100.0)
+    }
+
+    /// Whether the median time grew by more than `threshold` percent,
+    /// compared exactly so that a change of just `threshold` isn't flagged.
+    fn is_slower(&self, threshold: f64) -> bool {
+        self.baseline.is_some_and(|baseline| {
+            let (current, baseline) = (self.current.median, baseline.median);
+            current.as_nanos() as f64 * 100.0 > baseline.as_nanos() as f64 * (100.0 + threshold)
+        })
+    }
+}
+
+/// Pairs the latest result for each solver and input with the latest result
+/// for the same from the revision `baseline`, or by default from any earlier
+/// revision.
+fn comparisons<'a>(entries: &'a [Entry], baseline: Option<&str>) -> Vec<Comparison<'a>> {
+    let mut latest = BTreeMap::new();
+    for entry in entries {
+        let key = (
+            (entry.year, entry.day),
+            part_key(&entry.part),
+            entry.input_hash.as_str(),
+        );
+        latest.insert(key, entry);
+    }
+    let comparisons = latest.into_values().map(|current| {
+        let baseline = entries.iter().rev().find(|entry| {
+            let is_baseline = match baseline {
+                Some(rev) => entry.rev.starts_with(rev),
+                None => entry.rev != current.rev,
+            };
+            is_baseline
+                && !ptr::eq(*entry, current)
+                && (entry.year, entry.day, &entry.part)
+                    == (current.year, current.day, &current.part)
+                && entry.input_hash == current.input_hash
+        });
+        Comparison { current, baseline }
+    });
+    comparisons.collect()
+}
+
+/// Fails if any of `comparisons` slowed down by more than `threshold`
+/// percent.
+fn check(comparisons: &[Comparison], threshold: f64) -> Result<(), String> {
+    match comparisons
+        .iter()
+        .filter(|c| c.is_slower(threshold))
+        .count()
+    {
+        0 => Ok(()),
+        n => Err(format!("{n} solvers slowed down by more than {threshold}%")),
+    }
+}
+
+/// Compares the latest result for each solver and input with a baseline,
+/// failing if any slowed down by more than the threshold.
+pub fn compare(history: &HistoryArgs, args: &CompareArgs) -> Result<(), String> {
+    let entries = load(history)?;
+    let comparisons = comparisons(&entries, args.baseline.as_deref());
+    println!(
+        "{:>4}  {:>3}  {:>5}  {:<16}  {:>11}  {:<16}  {:>11}  {:>8}",
+        "Year", "Day", "Part", "Baseline", "Median (ms)", "Current", "Median (ms)", "Change"
+    );
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    for comparison in &comparisons {
+        let current = comparison.current;
+        let (year, day, part) = (current.year, current.day, &current.part);
+        let (Some(baseline), Some(change)) = (comparison.baseline, comparison.change()) else {
+            println!(
+                "{year:>4}  {day:>3}  {part:>5}  {:<16}  {:>11}  {:<16}  {:>11.3}",
+                "-",
+                "",
+                current.rev,
+                ms(current.median)
+            );
+            continue;
+        };
+        let flag = if comparison.is_slower(args.threshold) {
+            "  SLOWER"
+        } else {
+            ""
+        };
+        println!(
+            "{year:>4}  {day:>3}  {part:>5}  {:<16}  {:>11.3}  {:<16}  {:>11.3}  {:>+7.1}%{flag}",
+            baseline.rev,
+            ms(baseline.median),
+            current.rev,
+            ms(current.median),
+            change
+        );
+    }
+    check(&comparisons, args.threshold)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    fn entry(rev: &str, day: u32, part: &str, input_hash: &str, median_ns: u64) -> Entry {
+        Entry {
+            timestamp: 0,
+            rev: rev.to_string(),
+            year: 2024,
+            day,
+            part: part.to_string(),
+            input_hash: input_hash.to_string(),
+            median: Duration::from_nanos(median_ns),
+            min: Duration::from_nanos(median_ns),
+            iters: 10,
+        }
+    }
+
+    /// The day, part and baseline revision of each comparison, and whether
+    /// it is flagged as slower.
+    fn flagged(comparisons: &[Comparison], threshold: f64) -> Vec<(u32, String, String, bool)> {
+        let flagged = comparisons.iter().map(|c| {
+            let baseline = c.baseline.map_or("-", |baseline| &baseline.rev);
+            let (day, part) = (c.current.day, c.current.part.clone());
+            (day, part, baseline.to_string(), c.is_slower(threshold))
+        });
+        flagged.collect()
+    }
+
+    #[test]
+    fn test_compare() {
+        let entries = [
+            entry("old", 1, "parse", "x", 1000),
+            entry("old", 1, "1", "x", 1000),
+            entry("old", 1, "2", "x", 1000),
+            entry("old", 2, "1", "x", 1000),
+            // Only compared with results for the same input, so the latest
+            // result for this one has no baseline.
+            entry("old", 2, "1", "y", 100),
+            entry("new", 1, "parse", "x", 1099),
+            entry("new", 1, "1", "x", 1100),
+            entry("new", 1, "2", "x", 1101),
+            entry("new", 2, "1", "x", 900),
+            // Missing from the baseline.
+            entry("new", 3, "1", "x", 5000),
+        ];
+        let comparisons = comparisons(&entries, None);
+        let row = |day, part: &str, baseline: &str, slower| {
+            (day, part.to_string(), baseline.to_string(), slower)
+        };
+        assert_eq!(
+            flagged(&comparisons, 10.0),
+            [
+                row(1, "parse", "old", false),
+                row(1, "1", "old", false),
+                row(1, "2", "old", true),
+                row(2, "1", "old", false),
+                row(2, "1", "-", false),
+                row(3, "1", "-", false),
+            ]
+        );
+        assert_eq!(comparisons[1].change().map(f64::round), Some(10.0));
+        assert_eq!(comparisons[5].change(), None);
+        assert_eq!(
+            check(&comparisons, 10.0),
+            Err("1 solvers slowed down by more than 10%".to_string())
+        );
+        assert_eq!(check(&comparisons, 10.1), Ok(()));
+
+        // Against an explicit revision, the latest results can be the
+        // baseline's own, which aren't compared with themselves.
+        let comparisons = super::comparisons(&entries, Some("ol"));
+        assert_eq!(
+            comparisons[0].baseline.map(|e| e.median.as_nanos()),
+            Some(1000)
+        );
+        let comparisons = super::comparisons(&entries[..5], Some("old"));
+        assert!(comparisons.iter().all(|c| c.baseline.is_none()));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use adventofcode::{Params, Solver};
+
+#[derive(clap::Args, Debug)]
+pub struct InputArgs {
+    /// Read the puzzle input from FILE, or from stdin if FILE is `-`
+    #[arg(short, long, value_name = "FILE")]
+    input: Option<PathBuf>,
+
+    /// Read the puzzle input for day N of YEAR from DIR/YEAR/N.txt
+    /// [default: inputs]
+    #[arg(long, value_name = "DIR")]
+    inputs_dir: Option<PathBuf>,
+
+    /// Read the input for day N of YEAR from the sample
+    /// DIR/YEAR/samples/N/NAME.txt, and check the results against the
+    /// sample's NAME.answers
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    sample: Option<String>,
+
+    /// Set the parameter NAME of the selected days to VALUE, overriding its
+    /// default and any set by the sample's NAME.params
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let Some((name, value)) = s.split_once('=') else {
+        return Err("expected NAME=VALUE".to_string());
+    };
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|e| format!("{value:?}: {e}"))?;
+    Ok((name.trim().to_string(), value))
+}
+
+/// Applies the `name=value` lines of a sample's params file to `params`.
+/// A missing file sets nothing.
+pub fn read_params(path: &Path, params: &mut Params) -> Result<(), String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (name, value) =
+            parse_param(line).map_err(|e| format!("{}:{}: {e}", path.display(), i + 1))?;
+        params
+            .set(&name, value)
+            .map_err(|e| format!("{}:{}: {e}", path.display(), i + 1))?;
+    }
+    Ok(())
+}
+
+impl InputArgs {
+    /// Loads the input for `day` of `year`. `embedded` is the input baked into the
+    /// binary, if any, and is only used when no path was given explicitly.
+    pub fn load(
+        &self,
+        year: u32,
+        day: u32,
+        embedded: Option<&'static str>,
+    ) -> Result<String, String> {
+        if let (None, None, None, Some(input)) =
+            (&self.sample, &self.input, &self.inputs_dir, embedded)
+        {
+            return Ok(input.to_string());
+        }
+        match self.path(year, day) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read input from {}: {e}", path.display())),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| format!("Failed to read input from stdin: {e}"))
+            }
+        }
+    }
+
+    /// The file that the input for `day` of `year` is read from, unless it is
+    /// embedded, or `None` if it is read from stdin.
+    pub fn path(&self, year: u32, day: u32) -> Option<PathBuf> {
+        match (&self.sample, &self.input) {
+            (Some(name), _) => Some(self.samples_dir(year).join(format!("{day}/{name}.txt"))),
+            (None, Some(path)) if path.as_os_str() == "-" => None,
+            (None, Some(path)) => Some(path.clone()),
+            (None, None) => Some(self.year_dir(year).join(format!("{day}.txt"))),
+        }
+    }
+
+    /// The default location of the answers file for `year`, if inputs are
+    /// read from an inputs directory.
+    pub fn answers_path(&self, year: u32) -> Option<PathBuf> {
+        match (&self.input, &self.sample) {
+            (None, None) => Some(self.year_dir(year).join("answers.txt")),
+            _ => None,
+        }
+    }
+
+    /// The parameters for the day of `solver`: those of the sample, if one is
+    /// read, with any given by `--param` that the day declares.
+    pub fn params(&self, solver: &Solver) -> Result<Params, String> {
+        let mut params = Params::new(solver.params);
+        if let Some(name) = &self.sample {
+            let dir = self.samples_dir(solver.year).join(solver.day.to_string());
+            read_params(&dir.join(format!("{name}.params")), &mut params)?;
+        }
+        for (name, value) in &self.params {
+            // Parameters that the day doesn't declare are for other days.
+            if solver.params.iter().any(|param| param.name == name) {
+                params.set(name, *value)?;
+            }
+        }
+        Ok(params)
+    }
+
+    /// Checks that each `--param` is declared by at least one of `solvers`,
+    /// and is in range for each of them.
+    pub fn check_params(&self, solvers: &[&Solver]) -> Result<(), String> {
+        for (name, value) in &self.params {
+            let declared = solvers.iter().flat_map(|solver| solver.params);
+            let mut declared = declared.filter(|param| param.name == name).peekable();
+            if declared.peek().is_none() {
+                return Err(format!(
+                    "None of the selected days has a parameter {name:?}"
+                ));
+            }
+            for param in declared {
+                param.check(*value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The name of the sample to read inputs from, if any.
+    pub fn sample(&self) -> Option<&str> {
+        self.sample.as_deref()
+    }
+
+    /// The directory holding the inputs for `year`.
+    fn year_dir(&self, year: u32) -> PathBuf {
+        let dir = self.inputs_dir.as_deref().unwrap_or("inputs".as_ref());
+        dir.join(year.to_string())
+    }
+
+    /// The directory holding a subdirectory of samples for each day of
+    /// `year`.
+    pub fn samples_dir(&self, year: u32) -> PathBuf {
+        self.year_dir(year).join("samples")
+    }
+}
@@ -0,0 +1,34 @@
+//! Solutions to Advent of Code.
+//!
+//! Each year has a public module `yearYYYY`, and each of its days a module
+//! `dayN` with a unit struct `DayN` implementing [`Solution`], so a day can
+//! be used directly:
+//!
+//! ```
+//! use adventofcode::{year2024::day1::Day1, Solution};
+//!
+//! assert_eq!(Day1::solve("3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n"), Ok(11));
+//! ```
+//!
+//! [`SOLVERS`] lists every day and part for tools that run them generically.
+
+pub mod animate;
+pub mod answer;
+pub mod cancel;
+#[cfg(test)]
+mod differential;
+pub mod error;
+pub mod log;
+pub mod render;
+pub mod rng;
+#[cfg(test)]
+mod samples;
+pub mod solution;
+mod utils;
+
+pub use answer::{Answer, IntoAnswer};
+pub use error::Error;
+pub use solution::{Generated, Param, Params, Solution, Solver};
+
+// Registers every `yearYYYY/dayN.rs` module, which build.rs finds.
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
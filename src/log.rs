@@ -0,0 +1,158 @@
+//! Diagnostic output from solvers, which is off unless the runner turns it
+//! on. Solvers log with `info!`, `debug!` and `trace!`, from the least to
+//! the most detailed, and each message's target is the module it comes
+//! from, such as `year2024::day24`.
+
+use std::{
+    fmt,
+    io::Write,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Which messages to log, and where to.
+pub struct Config {
+    /// The most detailed level to log for every target, if any.
+    pub level: Option<Level>,
+    /// Targets to log every message from, each matching a module path or
+    /// the end of one, so that `day14` matches `year2024::day14`.
+    pub targets: Vec<String>,
+    pub output: Box<dyn Write + Send>,
+}
+
+impl Config {
+    fn enabled(&self, target: &str, level: Level) -> bool {
+        let matches = |filter: &String| {
+            let rest = target.strip_suffix(filter.as_str());
+            rest.is_some_and(|rest| rest.is_empty() || rest.ends_with("::"))
+        };
+        self.level.is_some_and(|max| level <= max) || self.targets.iter().any(matches)
+    }
+}
+
+/// The most detailed level that anything is logged at, checked first so
+/// that logging costs next to nothing while it is off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+static CONFIG: Mutex<Option<Config>> = Mutex::new(None);
+
+/// Starts logging the messages that `config` selects.
+pub fn init(config: Config) {
+    let max_level = match (&config.targets[..], config.level) {
+        ([], level) => level.map_or(0, |level| level as u8),
+        _ => Level::Trace as u8,
+    };
+    *CONFIG.lock().unwrap_or_else(PoisonError::into_inner) = Some(config);
+    MAX_LEVEL.store(max_level, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` from `target`, a module path, are logged.
+pub fn enabled(target: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let target = target.strip_prefix("adventofcode::").unwrap_or(target);
+    let config = CONFIG.lock().unwrap_or_else(PoisonError::into_inner);
+    config
+        .as_ref()
+        .is_some_and(|config| config.enabled(target, level))
+}
+
+/// Writes a message, which the macros only do once it is `enabled`.
+pub fn write(target: &str, level: Level, args: fmt::Arguments) {
+    let target = target.strip_prefix("adventofcode::").unwrap_or(target);
+    let mut config = CONFIG.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(config) = config.as_mut() {
+        // A failure to log shouldn't stop the solver.
+        let _ = writeln!(config.output, "{level:<5} {target}: {args}");
+    }
+}
+
+/// Logs a message at a level, formatting it only if it is logged.
+macro_rules! log {
+    ($level:ident, $($arg:tt)*) => {
+        if crate::log::enabled(module_path!(), crate::log::Level::$level) {
+            crate::log::write(module_path!(), crate::log::Level::$level, format_args!($($arg)*));
+        }
+    };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { crate::log::log!(Info, $($arg)*) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { crate::log::log!(Debug, $($arg)*) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => { crate::log::log!(Trace, $($arg)*) };
+}
+
+pub(crate) use {debug, info, log, trace};
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// Collects what is written to it, for checking the log.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_log() {
+        let buffer = Buffer::default();
+        init(Config {
+            level: None,
+            targets: vec!["log::test".to_string()],
+            output: Box::new(buffer.clone()),
+        });
+        trace!("Traced {}", 1);
+        assert!(!enabled("adventofcode::year2024::day14", Level::Info));
+        let config = Config {
+            level: Some(Level::Debug),
+            targets: vec!["day14".to_string()],
+            output: Box::new(std::io::sink()),
+        };
+        assert!(config.enabled("year2024::day14", Level::Trace));
+        assert!(!config.enabled("year2024::day4", Level::Trace));
+        assert!(config.enabled("year2024::day4", Level::Debug));
+        let log = buffer.0.lock().unwrap().clone();
+        assert_eq!(
+            String::from_utf8(log).unwrap(),
+            "TRACE log::test: Traced 1\n"
+        );
+    }
+}
@@ -1,42 +1,266 @@
+use std::{fs::File, io, path::PathBuf, process::ExitCode, time::Duration};
+
+use adventofcode::{animate, log, render, Solver, SOLVERS};
 use clap::Parser;
+use itertools::Itertools;
 
-mod utils;
+mod alloc;
+mod answers;
+mod bench;
+mod client;
+mod fetch;
+mod generate;
+mod history;
+mod input;
+mod report;
+mod runner;
+mod scaffold;
+mod select;
+mod submit;
+mod terminal;
+mod watch;
 
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
-    #[arg(value_enum, default_value_t=Task::Latest)]
-    task: Task,
-}
-
-utils::make_runner!(
-    1+,
-    2+,
-    3+,
-    4+,
-    5+,
-    6+,
-    7+,
-    8+,
-    9+,
-    10+,
-    11+,
-    12+,
-    13+,
-    14+,
-    15+,
-    16+,
-    17+,
-    18+,
-    19+,
-    20+,
-    21+,
-    22+,
-    23+,
-    24+,
-    25,
-);
-
-fn main() {
-    run(Args::parse());
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct LogArgs {
+    /// Log what the solvers are doing: `-v` for the main steps, `-vv` for
+    /// the details and `-vvv` for everything
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Log everything from TARGET, such as `day14` or `year2024::day14`,
+    /// whatever the verbosity
+    #[arg(long, value_name = "TARGET", global = true)]
+    trace: Vec<String>,
+
+    /// Write the log to FILE instead of stderr
+    #[arg(long, value_name = "FILE", global = true)]
+    log_file: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct Selection {
+    /// The year to run days from, or `all` [default: the latest year, or
+    /// every year when running `all` days]
+    #[arg(short, long)]
+    year: Option<select::Year>,
+
+    /// The days to run: a day like `5`, a range like `3..7` or `3..=7`,
+    /// `all` or `latest`
+    #[arg(default_value = "latest")]
+    days: select::Days,
+
+    /// The parts to run
+    #[arg(short, long, value_enum, default_value_t = select::Part::Both)]
+    part: select::Part,
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    #[command(flatten)]
+    input: input::InputArgs,
+
+    #[command(flatten)]
+    answers: answers::AnswerArgs,
+
+    /// Give up on a solver after this many seconds, moving on to the next day
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<f64>,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = report::Format::Text)]
+    format: report::Format,
+
+    /// Write the pictures that solvers draw to DIR, as a PNG for a single
+    /// picture and a GIF for an animation
+    #[arg(long, value_name = "DIR")]
+    render: Option<PathBuf>,
+
+    /// Animate the solvers' simulations in the terminal, step by step
+    #[arg(long)]
+    animate: bool,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Run the selected solvers once and check their answers (the default)
+    Run(RunArgs),
+
+    /// Time the selected solvers over many runs
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        #[command(flatten)]
+        input: input::InputArgs,
+
+        #[command(flatten)]
+        bench: bench::BenchArgs,
+
+        #[command(flatten)]
+        history: history::HistoryArgs,
+    },
+
+    /// Re-run the selected solvers and their samples whenever their input
+    /// or sample files change
+    Watch {
+        #[command(flatten)]
+        selection: Selection,
+
+        #[command(flatten)]
+        input: input::InputArgs,
+
+        #[command(flatten)]
+        watch: watch::WatchArgs,
+    },
+
+    /// Download the input for a day, unless it has already been downloaded
+    Fetch(fetch::FetchArgs),
+
+    /// Submit the answer to a part, unless the submissions log shows that
+    /// it would be wrong or too soon
+    Submit(submit::SubmitArgs),
+
+    /// Create the module, input and sample files for a new day
+    New(scaffold::NewArgs),
+
+    /// Generate a random input for a day, for stress testing
+    Generate(generate::GenerateArgs),
+
+    /// Compare the latest benchmark results with a baseline
+    Compare {
+        #[command(flatten)]
+        history: history::HistoryArgs,
+
+        #[command(flatten)]
+        compare: history::CompareArgs,
+    },
+}
+
+impl LogArgs {
+    /// Starts logging, unless nothing is to be logged.
+    fn init(&self) -> Result<(), String> {
+        let level = match self.verbose {
+            0 => None,
+            1 => Some(log::Level::Info),
+            2 => Some(log::Level::Debug),
+            _ => Some(log::Level::Trace),
+        };
+        if level.is_none() && self.trace.is_empty() {
+            return Ok(());
+        }
+        let output: Box<dyn io::Write + Send> = match &self.log_file {
+            Some(path) => Box::new(
+                File::create(path)
+                    .map_err(|e| format!("Failed to create {}: {e}", path.display()))?,
+            ),
+            None => Box::new(io::stderr()),
+        };
+        log::init(log::Config {
+            level,
+            targets: self.trace.clone(),
+            output,
+        });
+        Ok(())
+    }
+}
+
+impl Selection {
+    fn select(&self) -> Result<Vec<&'static Solver>, String> {
+        select::select(SOLVERS, self.year, &self.days, self.part)
+    }
+}
+
+fn run(args: Args) -> Result<(), runner::RunError> {
+    args.log.init()?;
+    match args.command.unwrap_or(Command::Run(args.run)) {
+        Command::Run(args) => {
+            let solvers = args.selection.select()?;
+            args.input.check_params(&solvers)?;
+            let years = solvers
+                .iter()
+                .map(|solver| solver.year)
+                .dedup()
+                .collect_vec();
+            let mut answers = answers::Answers::load(&args.answers, &args.input, &years)?;
+            let table = solvers
+                .iter()
+                .any(|solver| (solver.year, solver.day) != (solvers[0].year, solvers[0].day));
+            let mut report = report::Report::new(args.format, table);
+            let timeout = args.timeout.map(Duration::from_secs_f64);
+            if let Some(dir) = &args.render {
+                render::init(dir);
+            }
+            if args.animate {
+                animate::init(Box::new(terminal::Terminal::new()?));
+            }
+            let result = runner::run(&solvers, &args.input, timeout, &mut answers, &mut report);
+            animate::finish();
+            for path in render::finish()? {
+                eprintln!("Rendered {}", path.display());
+            }
+            result
+        }
+        Command::Bench {
+            selection,
+            input,
+            bench,
+            history,
+        } => {
+            let solvers = selection.select()?;
+            input.check_params(&solvers)?;
+            Ok(bench::bench(&solvers, &input, &bench, &history)?)
+        }
+        Command::Watch {
+            selection,
+            input,
+            watch,
+        } => {
+            let solvers = selection.select()?;
+            input.check_params(&solvers)?;
+            Ok(watch::watch(&solvers, &input, &watch)?)
+        }
+        Command::Fetch(args) => {
+            let latest_year = SOLVERS.last().map(|solver| solver.year);
+            Ok(fetch::fetch(&args, latest_year)?)
+        }
+        Command::Submit(args) => {
+            let latest_year = SOLVERS.last().map(|solver| solver.year);
+            Ok(submit::submit(&args, latest_year)?)
+        }
+        Command::New(args) => {
+            let latest_year = SOLVERS.last().map(|solver| solver.year);
+            Ok(scaffold::new(&args, latest_year)?)
+        }
+        Command::Generate(args) => {
+            let latest_year = SOLVERS.last().map(|solver| solver.year);
+            Ok(generate::run(&args, latest_year)?)
+        }
+        Command::Compare { history, compare } => Ok(history::compare(&history, &compare)?),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e.message);
+            ExitCode::from(e.status)
+        }
+    }
 }
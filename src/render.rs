@@ -0,0 +1,434 @@
+//! Pictures of grid-based puzzles, which solvers record as frames while
+//! the runner is rendering, and which are written out as PNG images or,
+//! for several frames, animated GIFs. The encoders are small ones of our
+//! own, which don't compress PNGs at all.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+/// The colour of each kind of cell, by the character that stands for it.
+/// The first is the background, and cells of other kinds are magenta.
+pub type Palette = &'static [(char, [u8; 3])];
+
+/// The colour of cells that aren't in the palette.
+const UNKNOWN: [u8; 3] = [255, 0, 255];
+
+/// A picture made of cells, each a colour from a small palette.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    colors: Vec<[u8; 3]>,
+    /// The index of each cell's colour, a row at a time.
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Draws each cell with the colour of the character that `cell` returns
+    /// for it, given its `(x, y)`.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        palette: Palette,
+        mut cell: impl FnMut(usize, usize) -> char,
+    ) -> Self {
+        assert!(
+            palette.len() < 256,
+            "A palette can have at most 255 colours"
+        );
+        let mut colors = palette.iter().map(|&(_, color)| color).collect::<Vec<_>>();
+        colors.push(UNKNOWN);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let c = cell(x, y);
+                let index = palette.iter().position(|&(key, _)| key == c);
+                pixels.push(index.unwrap_or(palette.len()) as u8);
+            }
+        }
+        Self {
+            width,
+            height,
+            colors,
+            pixels,
+        }
+    }
+
+    /// Draws rows of characters, filling out short rows with the
+    /// background.
+    pub fn from_rows(rows: &[impl AsRef<str>], palette: Palette) -> Self {
+        let rows: Vec<Vec<char>> = rows
+            .iter()
+            .map(|row| row.as_ref().chars().collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let background = palette.first().map_or(' ', |&(c, _)| c);
+        Self::from_fn(width, rows.len(), palette, |x, y| {
+            rows[y].get(x).copied().unwrap_or(background)
+        })
+    }
+
+    /// Blows each cell up into a square of `factor` by `factor` pixels.
+    pub fn scale(&self, factor: usize) -> Self {
+        let (width, height) = (self.width * factor, self.height * factor);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let row: Vec<u8> = row.iter().flat_map(|&p| [p].repeat(factor)).collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&row);
+            }
+        }
+        Self {
+            width,
+            height,
+            colors: self.colors.clone(),
+            pixels,
+        }
+    }
+
+    /// The colour of the cell at `(x, y)`.
+    pub fn color(&self, x: usize, y: usize) -> [u8; 3] {
+        self.colors[self.pixels[y * self.width + x] as usize]
+    }
+
+    fn rgb(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+        self.pixels.iter().map(|&p| self.colors[p as usize])
+    }
+
+    /// Encodes the image as a binary PPM.
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.rgb().flatten());
+        ppm
+    }
+
+    /// Encodes the image as a PNG, storing the pixels without compressing
+    /// them.
+    pub fn png(&self) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel of RGB, with the standard compression and
+        // filters and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        png_chunk(&mut png, b"IHDR", &header);
+        let rgb: Vec<u8> = self.rgb().flatten().collect();
+        let mut scanlines = Vec::with_capacity((3 * self.width + 1) * self.height);
+        for row in rgb.chunks(3 * self.width.max(1)) {
+            // Each row starts with its filter, which is none.
+            scanlines.push(0);
+            scanlines.extend_from_slice(row);
+        }
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        zlib.push(blocks.peek().is_none() as u8);
+        zlib.extend((block.len() as u16).to_le_bytes());
+        zlib.extend((!(block.len() as u16)).to_le_bytes());
+        zlib.extend(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    zlib.extend(((b << 16) | a).to_be_bytes());
+    zlib
+}
+
+/// Packs codes of varying widths into bytes, least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.bits |= (code as u32) << self.len;
+        self.len += width;
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses palette indices of `min_width` bits with GIF's variant of
+/// LZW, whose codes start a bit wider and grow up to 12 bits.
+fn lzw(pixels: &[u8], min_width: u32) -> Vec<u8> {
+    let clear = 1u16 << min_width;
+    let end = clear + 1;
+    let mut codes = std::collections::HashMap::new();
+    let (mut next, mut width) = (end + 1, min_width + 1);
+    let mut out = BitWriter::default();
+    out.write(clear, width);
+    let mut prefix: Option<u16> = None;
+    for &pixel in pixels {
+        let Some(code) = prefix else {
+            prefix = Some(pixel as u16);
+            continue;
+        };
+        if let Some(&longer) = codes.get(&(code, pixel)) {
+            prefix = Some(longer);
+            continue;
+        }
+        out.write(code, width);
+        if next < 4096 {
+            codes.insert((code, pixel), next);
+            next += 1;
+            // The decoder adds its codes a step behind, so it widens them
+            // only once the table has passed the current width.
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            out.write(clear, width);
+            codes.clear();
+            (next, width) = (end + 1, min_width + 1);
+        }
+        prefix = Some(pixel as u16);
+    }
+    if let Some(code) = prefix {
+        out.write(code, width);
+    }
+    out.write(end, width);
+    out.finish()
+}
+
+/// Encodes `frames`, which must all be the same size and use the same
+/// palette, as a GIF that loops forever, showing each frame for `delay`
+/// hundredths of a second.
+pub fn gif(frames: &[Image], delay: u16) -> Vec<u8> {
+    let first = &frames[0];
+    // The colour table's size is a power of two, of at least 4 colours.
+    let bits = (usize::BITS - (first.colors.len() - 1).leading_zeros()).max(2);
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend((first.width as u16).to_le_bytes());
+    gif.extend((first.height as u16).to_le_bytes());
+    gif.extend([0xf0 | (bits as u8 - 1), 0, 0]);
+    for i in 0..1 << bits {
+        gif.extend(first.colors.get(i).unwrap_or(&[0; 3]));
+    }
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+    for frame in frames {
+        assert_eq!(
+            (frame.width, frame.height, &frame.colors),
+            (first.width, first.height, &first.colors),
+            "Every frame of a GIF must be the same size and use the same palette"
+        );
+        gif.extend([0x21, 0xf9, 4, 0]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0, 0x2c, 0, 0, 0, 0]);
+        gif.extend((frame.width as u16).to_le_bytes());
+        gif.extend((frame.height as u16).to_le_bytes());
+        gif.extend([0, bits as u8]);
+        for block in lzw(&frame.pixels, bits).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3b);
+    gif
+}
+
+/// The frames recorded so far, by the target that recorded them and the
+/// name of their animation.
+struct Renderer {
+    dir: PathBuf,
+    frames: BTreeMap<(String, String), Vec<Image>>,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static RENDERER: Mutex<Option<Renderer>> = Mutex::new(None);
+
+/// Starts recording frames, to be written to `dir`.
+pub fn init(dir: &Path) {
+    *RENDERER.lock().unwrap_or_else(PoisonError::into_inner) = Some(Renderer {
+        dir: dir.to_path_buf(),
+        frames: BTreeMap::new(),
+    });
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether frames are being recorded.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records the next frame of the animation `name` from `target`, a module
+/// path, which the `frame!` macro only does while rendering.
+pub fn record(target: &str, name: &str, image: Image) {
+    let target = target.strip_prefix("adventofcode::").unwrap_or(target);
+    let mut renderer = RENDERER.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(renderer) = renderer.as_mut() {
+        let key = (target.replace("::", "-"), name.to_string());
+        renderer.frames.entry(key).or_default().push(image);
+    }
+}
+
+/// How large the longer side of a picture is drawn, at least.
+const MIN_PIXELS: usize = 400;
+
+/// Writes out everything recorded so far, an animation with a single frame
+/// as a PNG and any other as a GIF, returning the paths written to.
+pub fn finish() -> Result<Vec<PathBuf>, String> {
+    let mut renderer = RENDERER.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(renderer) = renderer.as_mut() else {
+        return Ok(Vec::new());
+    };
+    let error = |path: &Path, e| format!("Failed to write {}: {e}", path.display());
+    fs::create_dir_all(&renderer.dir).map_err(|e| error(&renderer.dir, e))?;
+    let mut paths = Vec::new();
+    for ((target, name), frames) in std::mem::take(&mut renderer.frames) {
+        let side = frames[0].width.max(frames[0].height).max(1);
+        let factor = (MIN_PIXELS / side).clamp(1, 16);
+        let frames: Vec<Image> = frames.iter().map(|frame| frame.scale(factor)).collect();
+        let (extension, bytes) = match &frames[..] {
+            [frame] => ("png", frame.png()),
+            _ => ("gif", gif(&frames, 10)),
+        };
+        let path = renderer.dir.join(format!("{target}-{name}.{extension}"));
+        fs::write(&path, bytes).map_err(|e| error(&path, e))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Records a frame of an animation, only building the `Image` while
+/// rendering.
+macro_rules! frame {
+    ($name:expr, $image:expr) => {
+        if crate::render::enabled() {
+            crate::render::record(module_path!(), $name, $image);
+        }
+    };
+}
+
+pub(crate) use frame;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PALETTE: Palette = &[('.', [0, 0, 0]), ('#', [255, 255, 255])];
+
+    /// Decodes GIF's LZW, to check that the encoder round trips.
+    fn unlzw(bytes: &[u8], min_width: u32) -> Vec<u8> {
+        let clear = 1usize << min_width;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut width = min_width + 1;
+        let (mut bits, mut len, mut bytes) = (0u32, 0, bytes.iter());
+        let mut out = Vec::new();
+        let mut last: Option<Vec<u8>> = None;
+        loop {
+            while len < width {
+                bits |= (*bytes.next().unwrap() as u32) << len;
+                len += 8;
+            }
+            let code = (bits & ((1 << width) - 1)) as usize;
+            (bits, len) = (bits >> width, len - width);
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                (width, last) = (min_width + 1, None);
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code), &last) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(last)) => [&last[..], &last[..1]].concat(),
+                (None, None) => panic!("Bad code {code}"),
+            };
+            if let Some(last) = last {
+                if table.len() < 4096 {
+                    table.push([&last[..], &entry[..1]].concat());
+                }
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+            out.extend(&entry);
+            last = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        let mut rng = crate::rng::Rng::new(0);
+        let noisy: Vec<u8> = (0..20000).map(|_| rng.below(4) as u8).collect();
+        let runs: Vec<u8> = (0..20000).map(|i| (i / 300 % 2) as u8).collect();
+        for pixels in [vec![], vec![1], noisy, runs] {
+            assert_eq!(unlzw(&lzw(&pixels, 2), 2), pixels);
+        }
+    }
+
+    #[test]
+    fn test_image() {
+        let image = Image::from_rows(&["#.", "?"], PALETTE);
+        assert_eq!((image.width, image.height), (2, 2));
+        let ppm = image.scale(2).ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..17], &[255, 255, 255, 255, 255, 255]);
+        assert_eq!(&ppm[11 + 24..11 + 30], &[255, 0, 255, 255, 0, 255]);
+        let png = image.png();
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+        let gif = gif(&[image.clone(), image], 10);
+        assert!(gif.starts_with(b"GIF89a\x02\x00\x02\x00"));
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
@@ -0,0 +1,310 @@
+use std::time::Duration;
+
+use adventofcode::{Answer, Error};
+
+use crate::{
+    alloc,
+    answers::Check,
+    runner::{Failure, Outcome},
+};
+
+#[derive(clap::ValueEnum, Copy, Clone, Debug)]
+pub enum Format {
+    /// A line per result, or a table when running every day
+    Text,
+    /// A JSON array with an object per result
+    Json,
+    /// A header row followed by a row per result
+    Csv,
+}
+
+/// Writes the outcome of each run to stdout in the chosen format.
+///
+/// The JSON and CSV formats share a schema, with a record per part:
+///
+/// - `year`, `day`, `part`: integers
+/// - `status`: `ok`, `panic`, `error` (the input could not be read or
+///   parsed, or has no answer), `timeout` or `skipped` (an earlier part of
+///   the day timed out)
+/// - `answer`: the answer, if `status` is `ok`
+/// - `duration_ns`: the time taken to solve the part, in nanoseconds
+/// - `parse_ns`: the time taken to parse the input, in nanoseconds. Both
+///   parts share the parsed input, so this is the same for each part.
+/// - `check`: `pass`, `fail` or `new`, if there is an answers file
+/// - `expected`: the expected answer, if `check` is `fail`
+/// - `error`: the panic or error message, if `status` is not `ok`
+/// - `allocations`, `allocated_bytes`: the number and total size of the
+///   allocations made while solving the part
+/// - `peak_bytes`: the most memory allocated at once while solving the part
+///
+/// The allocation fields are only set when built with the `count-allocs`
+/// feature.
+///
+/// Missing values are `null` in JSON and empty in CSV.
+pub struct Report {
+    format: Format,
+    table: bool,
+    total: Duration,
+    /// The year and day of the last row, whose parse time is counted.
+    last_day: Option<(u32, u32)>,
+    /// The last JSON row, held back until we know whether it needs a comma.
+    pending: Option<String>,
+}
+
+const FIELDS: [&str; 13] = [
+    "year",
+    "day",
+    "part",
+    "status",
+    "answer",
+    "duration_ns",
+    "parse_ns",
+    "check",
+    "expected",
+    "error",
+    "allocations",
+    "allocated_bytes",
+    "peak_bytes",
+];
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+pub fn parse_error(e: &Error) -> String {
+    format!("Failed to parse the input at {e}")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Report {
+    /// `table` selects the tabular text output used when running many days.
+    pub fn new(format: Format, table: bool) -> Self {
+        Self {
+            format,
+            table,
+            total: Duration::ZERO,
+            last_day: None,
+            pending: None,
+        }
+    }
+
+    pub fn begin(&mut self) {
+        match self.format {
+            Format::Text if self.table => {
+                print!(
+                    "{:>4}  {:>3}  {:>4}  {:>9}  {:>9}  ",
+                    "Year", "Day", "Part", "Parse (s)", "Solve (s)"
+                );
+                if alloc::ENABLED {
+                    print!("{:>9}  {:>10}  {:>10}  ", "Allocs", "Allocated", "Peak");
+                }
+                println!("{:<7}  Answer", "Status");
+            }
+            Format::Text => (),
+            Format::Json => println!("["),
+            Format::Csv => println!("{}", FIELDS.join(",")),
+        }
+    }
+
+    pub fn row(&mut self, outcome: &Outcome) {
+        self.total += outcome.duration;
+        let day = (outcome.solver.year, outcome.solver.day);
+        if self.last_day.replace(day) != Some(day) {
+            self.total += outcome.parse_duration;
+        }
+        match self.format {
+            Format::Text if self.table => Self::table_row(outcome),
+            Format::Text => Self::line(outcome),
+            Format::Json => {
+                let fields = self
+                    .fields(outcome)
+                    .map(|v| v.unwrap_or_else(|| "null".into()));
+                let fields = FIELDS
+                    .iter()
+                    .zip(fields)
+                    .map(|(k, v)| format!("\"{k}\": {v}"));
+                let row = format!("  {{{}}}", fields.collect::<Vec<_>>().join(", "));
+                if let Some(last) = self.pending.replace(row) {
+                    println!("{last},");
+                }
+            }
+            Format::Csv => {
+                let fields = self.fields(outcome).map(|v| v.unwrap_or_default());
+                println!("{}", fields.join(","));
+            }
+        }
+    }
+
+    pub fn end(&mut self) {
+        match self.format {
+            Format::Text if self.table => {
+                println!(
+                    "{:>4}  {:>3}  {:>4}  {:>9}  {:>9.3}",
+                    "All",
+                    "",
+                    "",
+                    "",
+                    self.total.as_secs_f32()
+                )
+            }
+            Format::Text | Format::Csv => (),
+            Format::Json => {
+                if let Some(last) = self.pending.take() {
+                    println!("{last}");
+                }
+                println!("]");
+            }
+        }
+    }
+
+    /// The values of `FIELDS` for `outcome`, encoded for the current format.
+    fn fields(&self, outcome: &Outcome) -> [Option<String>; 13] {
+        let text = |s: &str| match self.format {
+            Format::Json => json_string(s),
+            _ => csv_field(s),
+        };
+        let (status, answer, error) = match &outcome.result {
+            Ok(answer) => ("ok", Some(answer), None),
+            Err(Failure::Panic(e)) => ("panic", None, Some(e.clone())),
+            Err(Failure::Error(e)) => ("error", None, Some(e.clone())),
+            Err(Failure::Parse(e)) => ("error", None, Some(parse_error(e))),
+            Err(Failure::Timeout) => ("timeout", None, None),
+            Err(Failure::Skipped) => ("skipped", None, None),
+        };
+        let expected = match &outcome.check {
+            Some(Check::Fail(expected)) => Some(expected),
+            _ => None,
+        };
+        [
+            Some(outcome.solver.year.to_string()),
+            Some(outcome.solver.day.to_string()),
+            Some(outcome.solver.part.to_string()),
+            Some(text(status)),
+            answer.map(|answer| text(&answer.to_string())),
+            Some(outcome.duration.as_nanos().to_string()),
+            Some(outcome.parse_duration.as_nanos().to_string()),
+            (outcome.check.as_ref()).map(|check| text(&check.to_string().to_lowercase())),
+            expected.map(|expected| text(expected)),
+            error.map(|error| text(&error)),
+            (outcome.memory).map(|memory| memory.allocations.to_string()),
+            (outcome.memory).map(|memory| memory.bytes.to_string()),
+            (outcome.memory).map(|memory| memory.peak.to_string()),
+        ]
+    }
+
+    fn line(outcome: &Outcome) {
+        let label = outcome.solver.label();
+        let (duration, parse) = (outcome.duration, outcome.parse_duration);
+        let mut timing = format!(
+            "in {:.3} seconds (parsed in {:.3} seconds",
+            duration.as_secs_f32(),
+            parse.as_secs_f32()
+        );
+        if let Some(memory) = outcome.memory {
+            timing += &format!(
+                "; {} allocations totalling {}, {} peak",
+                memory.allocations,
+                alloc::format_bytes(memory.bytes),
+                alloc::format_bytes(memory.peak)
+            );
+        }
+        timing.push(')');
+        match (&outcome.result, &outcome.check) {
+            (Ok(answer), None) => println!("Computed result for {label} {timing}: {answer}"),
+            (Ok(answer), Some(Check::Fail(expected))) => println!(
+                "Computed result for {label} {timing}: {answer} [FAIL, expected {expected}]"
+            ),
+            (Ok(answer), Some(check)) => {
+                println!("Computed result for {label} {timing}: {answer} [{check}]")
+            }
+            (Err(Failure::Panic(e)), _) => eprintln!("{label} panicked: {e}"),
+            (Err(Failure::Error(e)), _) => eprintln!("{e}"),
+            (Err(Failure::Timeout), _) => {
+                eprintln!(
+                    "{label} timed out after {:.3} seconds",
+                    duration.as_secs_f32()
+                )
+            }
+            (Err(Failure::Skipped), _) => {
+                eprintln!("Skipped {label}, as an earlier part timed out")
+            }
+            (Err(Failure::Parse(e)), _) => {
+                eprintln!("Failed to parse the input for {label} at {e}");
+                eprintln!("{:>5} | {}", e.line, e.source_line);
+                eprintln!("{:>5} | {:>2$}", "", "^", e.column);
+            }
+        }
+        if let Some(picture) = outcome.result.as_ref().ok().and_then(Answer::picture) {
+            for row in picture {
+                println!("    {row}");
+            }
+        }
+    }
+
+    fn table_row(outcome: &Outcome) {
+        let (status, answer) = match (&outcome.result, &outcome.check) {
+            (Ok(answer), Some(Check::Fail(expected))) => (
+                "FAIL".to_string(),
+                format!("{answer} (expected {expected})"),
+            ),
+            (Ok(answer), Some(check)) => (check.to_string(), answer.to_string()),
+            (Ok(answer), None) => ("-".to_string(), answer.to_string()),
+            (Err(Failure::Panic(e)), _) => ("PANIC".to_string(), e.clone()),
+            (Err(Failure::Error(e)), _) => ("ERROR".to_string(), e.clone()),
+            (Err(Failure::Parse(e)), _) => ("ERROR".to_string(), parse_error(e)),
+            (Err(Failure::Timeout), _) => ("TIMEOUT".to_string(), String::new()),
+            (Err(Failure::Skipped), _) => ("SKIPPED".to_string(), String::new()),
+        };
+        let (year, day, part) = (outcome.solver.year, outcome.solver.day, outcome.solver.part);
+        let (parse, duration) = (outcome.parse_duration, outcome.duration);
+        let (parse, duration) = (parse.as_secs_f32(), duration.as_secs_f32());
+        let memory = match outcome.memory {
+            Some(memory) => format!(
+                "{:>9}  {:>10}  {:>10}  ",
+                memory.allocations,
+                alloc::format_bytes(memory.bytes),
+                alloc::format_bytes(memory.peak)
+            ),
+            None if alloc::ENABLED => format!("{:>9}  {:>10}  {:>10}  ", "", "", ""),
+            None => String::new(),
+        };
+        println!(
+            "{year:>4}  {day:>3}  {part:>4}  {parse:>9.3}  {duration:>9.3}  {memory}{status:<7}  {answer}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("123"), "123");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
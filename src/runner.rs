@@ -0,0 +1,314 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, catch_unwind, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use adventofcode::{
+    animate,
+    cancel::{self, Cancelled},
+    solution::Parsed,
+    Answer, Error, Params, Solver,
+};
+use itertools::Itertools;
+
+use crate::{
+    alloc::{self, Stats},
+    answers::{Answers, Check},
+    input::InputArgs,
+    report::Report,
+};
+
+/// Why a solver did not produce an answer.
+#[derive(Clone)]
+pub enum Failure {
+    Panic(String),
+    Error(String),
+    Parse(Error),
+    Timeout,
+    /// An earlier part of the day timed out, so this one never ran.
+    Skipped,
+}
+
+/// The result of running one solver.
+pub struct Outcome<'a> {
+    pub solver: &'a Solver,
+    pub result: Result<Answer, Failure>,
+    /// The time taken to parse the input, which is shared by every part.
+    pub parse_duration: Duration,
+    pub duration: Duration,
+    /// The allocations made while solving the part, if they are counted.
+    pub memory: Option<Stats>,
+    pub check: Option<Check>,
+}
+
+impl Outcome<'_> {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok() && !matches!(self.check, Some(Check::Fail(_)))
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Box<dyn Any>".to_string(),
+        },
+    }
+}
+
+thread_local! {
+    /// Whether `timed` is catching panics on this thread.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic that `timed` caught on this thread happened.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that keeps quiet about the panics that `timed`
+/// catches, which are reported along with the solver's result instead, and
+/// leaves any others to the default hook.
+fn quiet_caught_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(|location| location.to_string()));
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Times `f`, leaving out any time spent watching its animation, and
+/// catching any panic so that the caller can carry on. If `f` is cancelled,
+/// it fails with a timeout.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (Result<T, Failure>, Duration) {
+    quiet_caught_panics();
+    let catching = CATCHING.replace(true);
+    let (start, watched) = (Instant::now(), animate::watched());
+    let result = catch_unwind(AssertUnwindSafe(f));
+    let duration = start.elapsed().saturating_sub(animate::watched() - watched);
+    CATCHING.set(catching);
+    let result = result.map_err(|payload| {
+        if payload.is::<Cancelled>() {
+            return Failure::Timeout;
+        }
+        let message = panic_message(payload);
+        match LOCATION.take() {
+            Some(location) => Failure::Panic(format!("{message}, at {location}")),
+            None => Failure::Panic(message),
+        }
+    });
+    (result, duration)
+}
+
+/// Solves `solver`'s part of `parsed` with `timed`, failing if the input
+/// has no answer.
+pub fn solve(parsed: &dyn Parsed, solver: &Solver) -> (Result<Answer, Failure>, Duration) {
+    let (answer, duration) = timed(|| parsed.solve(solver.part));
+    let no_answer = |e| Failure::Error(format!("{} has no answer: {e}", solver.label()));
+    (
+        answer.and_then(|answer| answer.map_err(no_answer)),
+        duration,
+    )
+}
+
+/// Exit status when a solver timed out, as used by `timeout(1)`.
+const TIMEOUT_STATUS: u8 = 124;
+
+/// How long a cancelled worker has to stop before it is left running.
+const GRACE: Duration = Duration::from_secs(1);
+
+/// Stack size for worker threads, matching the usual main thread so that
+/// recursive solvers behave the same as they would there.
+const STACK_SIZE: usize = 8 << 20;
+
+/// Why a run failed, with the exit status to report it with.
+pub struct RunError {
+    pub message: String,
+    pub status: u8,
+}
+
+impl From<String> for RunError {
+    fn from(message: String) -> Self {
+        Self { message, status: 1 }
+    }
+}
+
+/// What a worker thread reports back as it parses and solves a day.
+enum Message {
+    Parsed(Result<(), Failure>, Duration),
+    Solved((Result<Answer, Failure>, Duration), Option<Stats>),
+}
+
+/// A worker thread parsing and solving a day.
+struct Worker {
+    receiver: Receiver<Message>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Worker {
+    /// Cancels the worker and waits a little for it to stop, returning
+    /// whether it did. A solver only stops where it checks for
+    /// cancellation, so one that doesn't carries on until the process exits.
+    fn stop(self) -> bool {
+        self.cancel.store(true, Ordering::Relaxed);
+        let deadline = Instant::now() + GRACE;
+        loop {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            match self.receiver.recv_timeout(timeout) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Disconnected) => return true,
+                Err(RecvTimeoutError::Timeout) => return false,
+            }
+        }
+    }
+}
+
+/// Parses `input` with `params` and solves each of `solvers` on a new thread,
+/// sending the results through the worker's channel.
+fn spawn(solvers: Vec<&'static Solver>, input: String, params: Params) -> Worker {
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let name = format!("{} day {}", solvers[0].year, solvers[0].day);
+    let flag = cancel.clone();
+    let worker = move || {
+        cancel::install(flag);
+        let (parsed, duration) = timed(|| (solvers[0].parse)(&input, &params));
+        let parsed = match parsed.and_then(|parsed| parsed.map_err(Failure::Parse)) {
+            Ok(parsed) => parsed,
+            Err(e) => return drop(sender.send(Message::Parsed(Err(e), duration))),
+        };
+        if sender.send(Message::Parsed(Ok(()), duration)).is_err() {
+            return;
+        }
+        for solver in solvers {
+            if cancel::cancelled() {
+                return;
+            }
+            let (solved, memory) = alloc::measure(|| solve(&*parsed, solver));
+            if sender.send(Message::Solved(solved, memory)).is_err() {
+                return;
+            }
+        }
+    };
+    thread::Builder::new()
+        .name(name)
+        .stack_size(STACK_SIZE)
+        .spawn(worker)
+        .expect("Failed to spawn a worker thread");
+    Worker { receiver, cancel }
+}
+
+/// Waits for the next message from a worker, or `None` if `timeout` passes
+/// first, not counting any time spent watching its animation.
+fn receive(receiver: &Receiver<Message>, timeout: Option<Duration>) -> Option<Message> {
+    let Some(timeout) = timeout else {
+        return receiver.recv().ok();
+    };
+    let (start, watched) = (Instant::now(), animate::watched());
+    let deadline = || start + timeout + (animate::watched() - watched);
+    loop {
+        match receiver.recv_timeout(deadline().saturating_duration_since(Instant::now())) {
+            // The deadline moved while the animation was being watched.
+            Err(RecvTimeoutError::Timeout) if Instant::now() < deadline() => continue,
+            result => return result.ok(),
+        }
+    }
+}
+
+/// Runs each solver once, checking its answer and passing the outcome to
+/// `report`. Each day runs on a worker thread, and a solver that takes longer
+/// than `timeout` is cancelled along with the rest of its day. Fails if any
+/// solver failed or gave the wrong answer.
+pub fn run(
+    solvers: &[&'static Solver],
+    args: &InputArgs,
+    timeout: Option<Duration>,
+    answers: &mut Answers,
+    report: &mut Report,
+) -> Result<(), RunError> {
+    let (mut failed, mut timed_out, mut still_running) = (0, Vec::new(), Vec::new());
+    let timed_out_after = timeout.unwrap_or_default();
+    report.begin();
+    for ((year, day), day_solvers) in &solvers.iter().group_by(|solver| (solver.year, solver.day)) {
+        let day_solvers = day_solvers.copied().collect_vec();
+        let input = args.load(year, day, day_solvers[0].embedded);
+        let params = input.and_then(|input| Ok((input, args.params(day_solvers[0])?)));
+        let worker = params.map(|(input, params)| spawn(day_solvers.clone(), input, params));
+        let (mut parsed, parse_duration) = match &worker {
+            Ok(worker) => match receive(&worker.receiver, timeout) {
+                Some(Message::Parsed(Ok(()), duration)) => (Ok(&worker.receiver), duration),
+                Some(Message::Parsed(Err(e), duration)) => (Err(e), duration),
+                _ => (Err(Failure::Timeout), timed_out_after),
+            },
+            Err(e) => (Err(Failure::Error(e.clone())), Duration::ZERO),
+        };
+        let mut day_timed_out = false;
+        for solver in day_solvers {
+            let ((result, duration), memory) = match &parsed {
+                Ok(receiver) => match receive(receiver, timeout) {
+                    Some(Message::Solved(solved, memory)) => (solved, memory),
+                    _ => ((Err(Failure::Timeout), timed_out_after), None),
+                },
+                Err(e) => ((Err(e.clone()), Duration::ZERO), None),
+            };
+            if let Err(Failure::Timeout) = result {
+                // The worker is still busy, so the day's later parts can't run.
+                parsed = Err(Failure::Skipped);
+                day_timed_out = true;
+                timed_out.push(solver.label());
+            }
+            let check = match &result {
+                Ok(answer) => answers.check(solver, answer),
+                Err(_) => None,
+            };
+            let outcome = Outcome {
+                solver,
+                result,
+                parse_duration,
+                duration,
+                memory,
+                check,
+            };
+            if !outcome.is_ok() {
+                failed += 1;
+            }
+            report.row(&outcome);
+        }
+        if let (Ok(worker), true) = (worker, day_timed_out) {
+            if !worker.stop() {
+                still_running.push(format!("{year} day {day}"));
+            }
+        }
+    }
+    report.end();
+    answers.save()?;
+    let message = format!("{failed} of {} solvers failed", solvers.len());
+    let mut timeouts = format!("{message}; timed out: {}", timed_out.join(", "));
+    if !still_running.is_empty() {
+        // Anything after a day that kept running shared the CPU with it.
+        timeouts += &format!(
+            " ({} kept running, so later timings are unreliable)",
+            still_running.join(", ")
+        );
+    }
+    match (failed, timed_out.is_empty()) {
+        (0, _) => Ok(()),
+        (_, true) => Err(message.into()),
+        (_, false) => Err(RunError {
+            message: timeouts,
+            status: TIMEOUT_STATUS,
+        }),
+    }
+}
@@ -0,0 +1,19 @@
+//! Tests for the samples under `inputs/YEAR/samples`, generated by `build.rs`.
+
+use crate::{IntoAnswer, Params, Solution};
+
+fn check<S: Solution>(input: &str, overrides: &[(&str, i64)], part: u32, expected: &str) {
+    let mut params = Params::new(S::PARAMS);
+    for &(name, value) in overrides {
+        params.set(name, value).unwrap();
+    }
+    let parsed = S::parse_with(input, &params).unwrap();
+    let answer = match part {
+        1 => S::part1(&parsed).into_answer(),
+        _ => S::part2(&parsed).into_answer(),
+    };
+    let answer = answer.unwrap_or_else(|e| panic!("{e}, not {expected}"));
+    assert!(answer.matches(expected), "{answer} is not {expected}");
+}
+
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));
@@ -0,0 +1,96 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct NewArgs {
+    /// The day to create
+    day: u32,
+
+    /// The year to create it in [default: the latest registered year]
+    #[arg(short, long)]
+    year: Option<u32>,
+}
+
+/// The module for a new day, with `DAY` standing for its number.
+const TEMPLATE: &str = "\
+use crate::{solution::Solution, Error};
+
+pub struct DayDAY;
+
+impl Solution for DayDAY {
+    type Parsed<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(_lines: &Self::Parsed<'_>) -> usize {
+        todo!()
+    }
+
+    fn part2(_lines: &Self::Parsed<'_>) -> usize {
+        todo!()
+    }
+}
+";
+
+fn render(day: u32) -> String {
+    TEMPLATE.replace("DAY", &day.to_string())
+}
+
+/// Writes `contents` to `path` under `root`, creating its directory, unless
+/// the file already exists. Returns whether it was created.
+fn create(root: &Path, path: PathBuf, contents: &str) -> Result<bool, String> {
+    let full_path = root.join(&path);
+    let error = |e: io::Error| format!("Failed to create {}: {e}", path.display());
+    if full_path.exists() {
+        return Ok(false);
+    }
+    fs::create_dir_all(full_path.parent().unwrap()).map_err(error)?;
+    fs::write(&full_path, contents).map_err(error)?;
+    println!("Created {}", path.display());
+    Ok(true)
+}
+
+/// Creates the module for a new day from a template, along with an empty
+/// input and an empty sample `a` with its answers file. The module is
+/// registered by build.rs the next time the crate is built.
+pub fn new(args: &NewArgs, latest_year: Option<u32>) -> Result<(), String> {
+    let year = args
+        .year
+        .or(latest_year)
+        .ok_or("No years are registered yet, so a --year is needed")?;
+    let day = args.day;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let module = PathBuf::from(format!("src/year{year}/day{day}.rs"));
+    if !create(root, module.clone(), &render(day))? {
+        return Err(format!("{} already exists", module.display()));
+    }
+    let inputs = PathBuf::from(format!("inputs/{year}"));
+    create(root, inputs.join(format!("{day}.txt")), "")?;
+    let sample = inputs.join(format!("samples/{day}/a"));
+    create(root, sample.with_extension("txt"), "")?;
+    create(root, sample.with_extension("answers"), "")?;
+    println!(
+        "Add the example to {} and its answers to {} as `part answer` lines",
+        sample.with_extension("txt").display(),
+        sample.with_extension("answers").display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let module = render(7);
+        assert!(module.contains("pub struct Day7;"));
+        assert!(module.contains("impl Solution for Day7 {"));
+    }
+}
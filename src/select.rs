@@ -0,0 +1,152 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use adventofcode::Solver;
+
+/// Which days to run: `latest`, `all`, a day number like `5`, or a range
+/// like `3..7`, `3..=7`, `20..` or `..5`. Ranges follow Rust's syntax, so
+/// `3..7` does not include day 7.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Days {
+    Latest,
+    All,
+    One(u32),
+    Range(RangeInclusive<u32>),
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| s.parse::<u32>().map_err(|_| format!("invalid day `{s}`"));
+        Ok(match s {
+            "latest" => Days::Latest,
+            "all" => Days::All,
+            _ => match s.split_once("..") {
+                None => Days::One(day(s)?),
+                Some((start, end)) => {
+                    let start = if start.is_empty() { 1 } else { day(start)? };
+                    let end = match end.strip_prefix('=') {
+                        Some(end) => day(end)?,
+                        None if end.is_empty() => u32::MAX,
+                        None => day(end)?.saturating_sub(1),
+                    };
+                    Days::Range(start..=end)
+                }
+            },
+        })
+    }
+}
+
+/// Which year to run days from: a year like `2024`, or `all`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Year {
+    All,
+    One(u32),
+}
+
+impl FromStr for Year {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Year::All),
+            _ => s
+                .parse()
+                .map(Year::One)
+                .map_err(|_| format!("invalid year `{s}`")),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Copy, Clone, Debug)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Part {
+    fn contains(self, part: u32) -> bool {
+        match self {
+            Part::One => part == 1,
+            Part::Two => part == 2,
+            Part::Both => true,
+        }
+    }
+}
+
+/// Returns the registered solvers for the chosen year, days and parts.
+/// Without a year, days come from the latest year, unless every day was
+/// asked for, which spans every year. A single day must be registered with
+/// the requested part, while a range only has to match at least one solver.
+pub fn select<'a>(
+    solvers: &'a [Solver],
+    year: Option<Year>,
+    days: &Days,
+    part: Part,
+) -> Result<Vec<&'a Solver>, String> {
+    let year = match (year, days) {
+        (Some(Year::All), _) | (None, Days::All) => None,
+        (Some(Year::One(year)), _) => Some(year),
+        (None, _) => Some(solvers.last().ok_or("No days are registered")?.year),
+    };
+    let solvers: Vec<_> = solvers
+        .iter()
+        .filter(|solver| year.is_none_or(|year| solver.year == year))
+        .collect();
+    if let (Some(year), true) = (year, solvers.is_empty()) {
+        return Err(format!("Year {year} is not registered"));
+    }
+    let (range, single) = match days {
+        Days::Latest => {
+            let latest = solvers.last().ok_or("No days are registered")?.day;
+            (latest..=latest, true)
+        }
+        Days::All => (0..=u32::MAX, false),
+        &Days::One(day) => (day..=day, true),
+        Days::Range(range) => (range.clone(), false),
+    };
+    let days: Vec<_> = solvers
+        .into_iter()
+        .filter(|solver| range.contains(&solver.day))
+        .collect();
+    let selected: Vec<_> = days
+        .iter()
+        .copied()
+        .filter(|solver| part.contains(solver.part))
+        .collect();
+    let day = range.start();
+    match part {
+        _ if single && days.is_empty() => Err(format!("Day {day} is not registered")),
+        Part::One if single && selected.is_empty() => Err(format!("Day {day} has no part 1")),
+        Part::Two if single && selected.is_empty() => Err(format!("Day {day} has no part 2")),
+        _ if selected.is_empty() => Err("No registered days match the selection".to_string()),
+        _ => Ok(selected),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!("latest".parse(), Ok(Days::Latest));
+        assert_eq!("all".parse(), Ok(Days::All));
+        assert_eq!("5".parse(), Ok(Days::One(5)));
+        assert_eq!("3..7".parse(), Ok(Days::Range(3..=6)));
+        assert_eq!("3..=7".parse(), Ok(Days::Range(3..=7)));
+        assert_eq!("20..".parse(), Ok(Days::Range(20..=u32::MAX)));
+        assert_eq!("..5".parse(), Ok(Days::Range(1..=4)));
+        assert!("5..x".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!("all".parse(), Ok(Year::All));
+        assert_eq!("2024".parse(), Ok(Year::One(2024)));
+        assert!("latest".parse::<Year>().is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{rng::Rng, Answer, Error, IntoAnswer};
+
+/// A named constant that a solution depends on, such as the size of a grid
+/// that is smaller in the puzzle's examples than in the real input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value for the real input.
+    pub default: i64,
+    /// The smallest and largest values that the solution can handle.
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Param {
+    /// Checks that `value` is in the parameter's range.
+    pub fn check(&self, value: i64) -> Result<(), String> {
+        if (self.min..=self.max).contains(&value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "The parameter {:?} must be from {} to {}, not {value}",
+                self.name, self.min, self.max
+            ))
+        }
+    }
+}
+
+/// The value of each of a solution's parameters: its default, unless it
+/// has been overridden.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params(Vec<(Param, i64)>);
+
+impl Params {
+    pub fn new(declared: &[Param]) -> Self {
+        Self(declared.iter().map(|&p| (p, p.default)).collect())
+    }
+
+    /// Overrides the parameter `name`, failing if it isn't declared or
+    /// `value` is out of its range.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match self
+            .0
+            .iter_mut()
+            .find(|(declared, _)| declared.name == name)
+        {
+            Some((declared, old)) => {
+                declared.check(value)?;
+                *old = value;
+                Ok(())
+            }
+            None => Err(format!("There is no parameter {name:?}")),
+        }
+    }
+
+    /// The value of the parameter `name`, which must be declared.
+    pub fn get(&self, name: &str) -> i64 {
+        match self.0.iter().find(|(declared, _)| declared.name == name) {
+            Some(&(_, value)) => value,
+            None => panic!("There is no parameter {name:?}"),
+        }
+    }
+}
+
+/// A randomly generated input, along with any parameters that have to be
+/// overridden to solve it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(&'static str, i64)>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            params: Vec::new(),
+        }
+    }
+
+    pub fn param(mut self, name: &'static str, value: i64) -> Self {
+        self.params.push((name, value));
+        self
+    }
+}
+
+/// A day's puzzle. The input is parsed once, and both parts are solved
+/// from the parsed input so that they can share work.
+pub trait Solution {
+    type Parsed<'a>;
+    type Answer1: IntoAnswer;
+    type Answer2: IntoAnswer;
+
+    /// The constants that the solution takes from `Params` rather than
+    /// hardcoding, with their values for the real input.
+    const PARAMS: &'static [Param] = &[];
+
+    /// How many parts the puzzle has. A day with only one, like the last
+    /// of each year, still implements `part2` with an `Answer2` of
+    /// `Infallible`, but doesn't get a solver for it.
+    const PARTS: u32 = 2;
+
+    /// Parses `input`, with the default value of each of `PARAMS`. Each
+    /// solution implements either this or `parse_with`.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    /// Parses `input` with the given values of `PARAMS`, which solutions
+    /// that declare parameters keep in their `Parsed`.
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        Self::parse(input)
+    }
+
+    /// Generates a valid random input for stress testing, whose size grows
+    /// with `size` in a way that depends on the puzzle, or `None` if the
+    /// solution has no generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<Generated> {
+        None
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Answer1;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Answer2;
+
+    /// A slow but obviously correct solution to part 1, which the
+    /// differential tests check `part1` against on generated inputs, or
+    /// `None` if the day has none or it is too slow for this input.
+    fn reference1(_parsed: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+        None
+    }
+
+    /// A slow but obviously correct solution to part 2, like `reference1`.
+    fn reference2(_parsed: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+        None
+    }
+
+    /// Parses `input` and solves part 1.
+    fn solve(input: &str) -> Result<Self::Answer1, Error> {
+        Ok(Self::part1(&Self::parse(input)?))
+    }
+
+    /// Parses `input` and solves part 2.
+    fn solve_2(input: &str) -> Result<Self::Answer2, Error> {
+        Ok(Self::part2(&Self::parse(input)?))
+    }
+}
+
+/// A parsed input with its `Solution` erased, so that the runner can
+/// handle every day alike.
+pub trait Parsed {
+    /// Solves `part`, or says why the input has no answer.
+    fn solve(&self, part: u32) -> Result<Answer, String>;
+
+    /// Solves `part` with the day's reference solution, if it has one.
+    fn reference(&self, part: u32) -> Option<Result<Answer, String>>;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Parsed<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: u32) -> Result<Answer, String> {
+        match part {
+            1 => S::part1(&self.0).into_answer(),
+            2 => S::part2(&self.0).into_answer(),
+            _ => panic!("There is no part {part}"),
+        }
+    }
+
+    fn reference(&self, part: u32) -> Option<Result<Answer, String>> {
+        match part {
+            1 => S::reference1(&self.0).map(IntoAnswer::into_answer),
+            2 => S::reference2(&self.0).map(IntoAnswer::into_answer),
+            _ => panic!("There is no part {part}"),
+        }
+    }
+}
+
+pub fn parse<'a, S: Solution + 'static>(
+    input: &'a str,
+    params: &Params,
+) -> Result<Box<dyn Parsed + 'a>, Error> {
+    Ok(Box::new(ParsedInput::<S>(S::parse_with(input, params)?)))
+}
+
+/// Parses a day's input with its parameters, erasing its `Solution`.
+pub type ParseFn = for<'a> fn(&'a str, &Params) -> Result<Box<dyn Parsed + 'a>, Error>;
+
+/// A single part of a single day, as registered in `SOLVERS`.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub embedded: Option<&'static str>,
+    /// The day's parameters, with their defaults.
+    pub params: &'static [Param],
+    pub parse: ParseFn,
+    pub generate: fn(&mut Rng, usize) -> Option<Generated>,
+}
+
+/// How many of `solvers`, each paired with whether its day has that part,
+/// are kept by `existing`.
+pub(crate) const fn count_existing(solvers: &[(Solver, bool)]) -> usize {
+    let (mut count, mut i) = (0, 0);
+    while i < solvers.len() {
+        count += solvers[i].1 as usize;
+        i += 1;
+    }
+    count
+}
+
+/// The `N` solvers in `solvers` for parts that their day has, in order.
+pub(crate) const fn existing<const N: usize>(solvers: &[(Solver, bool)]) -> [Solver; N] {
+    let mut existing = [solvers[0].0; N];
+    let (mut count, mut i) = (0, 0);
+    while i < solvers.len() {
+        if solvers[i].1 {
+            existing[count] = solvers[i].0;
+            count += 1;
+        }
+        i += 1;
+    }
+    existing
+}
+
+impl Solver {
+    pub fn label(&self) -> String {
+        match self.part {
+            1 => format!("{} day {}", self.year, self.day),
+            part => format!("{} day {} (part {part})", self.year, self.day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        const PARAMS: &[Param] = &[
+            Param {
+                name: "width",
+                default: 101,
+                min: 1,
+                max: 1000,
+            },
+            Param {
+                name: "height",
+                default: 103,
+                min: 1,
+                max: 1000,
+            },
+        ];
+        let mut params = Params::new(PARAMS);
+        assert_eq!(params.set("width", 11), Ok(()));
+        assert!(params.set("depth", 1).is_err());
+        assert!(params.set("height", 0).is_err());
+        assert!(params.set("height", -1).is_err());
+        assert_eq!((params.get("width"), params.get("height")), (11, 103));
+    }
+
+    #[test]
+    fn test_existing() {
+        let solver = |day, part| Solver {
+            year: 2024,
+            day,
+            part,
+            embedded: None,
+            params: &[],
+            parse: parse::<crate::year2024::day25::Day25>,
+            generate: |_, _| None,
+        };
+        let all = [
+            (solver(24, 1), true),
+            (solver(24, 2), true),
+            (solver(25, 1), true),
+            (solver(25, 2), false),
+        ];
+        let existing = existing::<3>(&all).map(|solver| (solver.day, solver.part));
+        assert_eq!(count_existing(&all), 3);
+        assert_eq!(existing, [(24, 1), (24, 2), (25, 1)]);
+        let labels = crate::SOLVERS.iter().filter(|solver| solver.day == 25);
+        assert_eq!(
+            labels.map(Solver::label).collect::<Vec<_>>(),
+            ["2024 day 25"]
+        );
+    }
+}
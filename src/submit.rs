@@ -0,0 +1,406 @@
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use adventofcode::{Params, SOLVERS};
+use regex::Regex;
+
+use crate::{
+    client::{Client, ClientArgs},
+    history, report,
+    runner::{timed, Failure},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct SubmitArgs {
+    /// The day to submit an answer for
+    day: u32,
+
+    /// The part to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+
+    /// The year of the puzzle [default: the latest registered year]
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// Submit ANSWER instead of solving the puzzle input
+    #[arg(long, value_name = "ANSWER")]
+    answer: Option<String>,
+
+    /// Read the input for day N of YEAR from DIR/YEAR/N.txt, and record
+    /// submissions in DIR/submissions.txt
+    #[arg(long, value_name = "DIR", default_value = "inputs")]
+    inputs_dir: PathBuf,
+
+    /// Wait at least SECS seconds between submissions
+    #[arg(long, value_name = "SECS", default_value_t = 60)]
+    cooldown: u64,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+/// How the server judged a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way.
+    Wrong,
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+    /// Submitted too soon after the last answer, with the seconds left to
+    /// wait. The answer wasn't checked.
+    Wait(u64),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+            Outcome::Wait(secs) => write!(f, "wait-{secs}"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "already-solved" => Outcome::AlreadySolved,
+            _ => {
+                let secs = s.strip_prefix("wait-").ok_or(())?;
+                Outcome::Wait(secs.parse().map_err(|_| ())?)
+            }
+        })
+    }
+}
+
+/// Works out how the server judged a submission from the page it returned.
+fn parse_response(page: &str) -> Option<Outcome> {
+    if page.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Outcome::AlreadySolved)
+    } else if page.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let captures = wait.captures(page)?;
+        let minutes = captures.get(1).map_or("0", |m| m.as_str());
+        let (minutes, seconds): (u64, u64) = (minutes.parse().ok()?, captures[2].parse().ok()?);
+        Some(Outcome::Wait(minutes * 60 + seconds))
+    } else if page.contains("That's not the right answer") {
+        Some(if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        })
+    } else {
+        None
+    }
+}
+
+/// A line of the submissions log: `timestamp year day part outcome answer`.
+struct Submission {
+    timestamp: u64,
+    year: u32,
+    day: u32,
+    part: u32,
+    outcome: Outcome,
+    answer: String,
+}
+
+fn parse_submission(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(6, ' ');
+    Some(Submission {
+        timestamp: fields.next()?.parse().ok()?,
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        outcome: fields.next()?.parse().ok()?,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+fn load(path: &Path) -> Result<Vec<Submission>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+    let lines = contents.lines().enumerate();
+    let mut submissions = Vec::new();
+    for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        let Some(submission) = parse_submission(line.trim()) else {
+            let path = path.display();
+            return Err(format!(
+                "{path}:{}: expected `timestamp year day part outcome answer`",
+                i + 1
+            ));
+        };
+        submissions.push(submission);
+    }
+    Ok(submissions)
+}
+
+fn append(path: &Path, submission: &Submission) -> Result<(), String> {
+    let error = |e: io::Error| format!("Failed to write {}: {e}", path.display());
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(error)?;
+    let Submission {
+        timestamp,
+        year,
+        day,
+        part,
+        outcome,
+        answer,
+    } = submission;
+    writeln!(file, "{timestamp} {year} {day} {part} {outcome} {answer}").map_err(error)
+}
+
+/// Checks the log for reasons not to send `submission`: the part is
+/// already solved, the same answer was already wrong, a number is beyond
+/// one already known to be too high or too low, or it is too soon after
+/// the last submission.
+fn check(log: &[Submission], submission: &Submission, cooldown: u64) -> Result<(), String> {
+    let answer = submission.answer.as_str();
+    let number = answer.parse::<i64>().ok();
+    let key = (submission.year, submission.day, submission.part);
+    for earlier in log.iter().filter(|s| (s.year, s.day, s.part) == key) {
+        let earlier_number = earlier.answer.parse::<i64>().ok();
+        match (earlier.outcome, number.zip(earlier_number)) {
+            (Outcome::Correct, _) => {
+                return Err(format!("Already solved with {}", earlier.answer));
+            }
+            (Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong, _)
+                if earlier.answer == answer =>
+            {
+                let outcome = earlier.outcome.to_string().replace('-', " ");
+                return Err(format!("{answer} was already submitted, and was {outcome}"));
+            }
+            (Outcome::TooHigh, Some((n, high))) if n >= high => {
+                return Err(format!("{answer} is too high, as {high} already was"));
+            }
+            (Outcome::TooLow, Some((n, low))) if n <= low => {
+                return Err(format!("{answer} is too low, as {low} already was"));
+            }
+            _ => (),
+        }
+    }
+    let ready_at = |s: &Submission| match s.outcome {
+        Outcome::Wait(secs) => s.timestamp + secs.max(cooldown),
+        _ => s.timestamp + cooldown,
+    };
+    match log.iter().map(ready_at).max() {
+        Some(ready_at) if ready_at > submission.timestamp => Err(format!(
+            "Wait {} more seconds before submitting again",
+            ready_at - submission.timestamp
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Sends `submission` unless the log at `path` shows that it shouldn't be,
+/// and records how it was judged there.
+fn send(
+    client: impl FnOnce() -> Result<Client, String>,
+    path: &Path,
+    submission: Submission,
+    cooldown: u64,
+) -> Result<Outcome, String> {
+    check(&load(path)?, &submission, cooldown)?;
+    let Submission {
+        year, day, part, ..
+    } = submission;
+    let page = client()?.submit(year, day, part, &submission.answer)?;
+    let outcome = parse_response(&page).ok_or("Couldn't make sense of the server's response")?;
+    append(
+        path,
+        &Submission {
+            outcome,
+            ..submission
+        },
+    )?;
+    Ok(outcome)
+}
+
+/// Solves the part being submitted on its input.
+fn solve(args: &SubmitArgs, year: u32) -> Result<String, String> {
+    let (day, part) = (args.day, args.part);
+    let solver = SOLVERS
+        .iter()
+        .find(|solver| (solver.year, solver.day, solver.part) == (year, day, part))
+        .ok_or(format!("{year} day {day} part {part} is not registered"))?;
+    let path = args.inputs_dir.join(format!("{year}/{day}.txt"));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read input from {}: {e}", path.display()))?;
+    let params = Params::new(solver.params);
+    let (answer, _) = timed(|| (solver.parse)(&input, &params).map(|parsed| parsed.solve(part)));
+    match answer {
+        Ok(Ok(Ok(answer))) => Ok(answer.to_string()),
+        Ok(Ok(Err(e))) => Err(format!("{} has no answer: {e}", solver.label())),
+        Ok(Err(e)) => Err(report::parse_error(&e)),
+        Err(Failure::Panic(e)) => Err(format!("{} panicked: {e}", solver.label())),
+        Err(_) => unreachable!("timed only fails with a panic"),
+    }
+}
+
+/// Submits the answer to a part, solving it first unless it was given, and
+/// reports how it was judged. Fails unless the answer was correct.
+pub fn submit(args: &SubmitArgs, latest_year: Option<u32>) -> Result<(), String> {
+    let year = args
+        .year
+        .or(latest_year)
+        .ok_or("No years are registered, so a --year is needed")?;
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => solve(args, year)?,
+    };
+    let (day, part) = (args.day, args.part);
+    println!("Submitting {answer} for {year} day {day} part {part}");
+    let submission = Submission {
+        timestamp: history::now(),
+        year,
+        day,
+        part,
+        outcome: Outcome::Wrong,
+        answer,
+    };
+    let path = args.inputs_dir.join("submissions.txt");
+    let client = || Client::from_args(&args.client);
+    match send(client, &path, submission, args.cooldown)? {
+        Outcome::Correct => {
+            println!("That's the right answer");
+            Ok(())
+        }
+        Outcome::AlreadySolved => {
+            println!("This part was already solved");
+            Ok(())
+        }
+        Outcome::TooHigh => Err("That's not the right answer; it's too high".into()),
+        Outcome::TooLow => Err("That's not the right answer; it's too low".into()),
+        Outcome::Wrong => Err("That's not the right answer".into()),
+        Outcome::Wait(secs) => Err(format!("Answered too recently; wait {secs} seconds")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, process};
+
+    use super::*;
+    use crate::client::stub::{response, Server};
+
+    fn submission(timestamp: u64, outcome: Outcome, answer: &str) -> Submission {
+        Submission {
+            timestamp,
+            year: 2024,
+            day: 1,
+            part: 1,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Outcome::AlreadySolved,
+            ),
+            (
+                "You gave an answer too recently. You have 4m 48s left to wait.",
+                Outcome::Wait(288),
+            ),
+            (
+                "You gave an answer too recently. You have 30s left to wait.",
+                Outcome::Wait(30),
+            ),
+        ];
+        for (text, outcome) in cases {
+            assert_eq!(parse_response(&page(text)), Some(outcome), "{text}");
+        }
+        assert_eq!(parse_response(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let log = [
+            submission(100, Outcome::TooHigh, "50"),
+            submission(200, Outcome::TooLow, "10"),
+        ];
+        let check =
+            |timestamp, answer| check(&log, &submission(timestamp, Outcome::Wrong, answer), 60);
+        assert_eq!(check(1000, "20"), Ok(()));
+        assert!(check(1000, "10").unwrap_err().contains("already submitted"));
+        assert!(check(1000, "60").unwrap_err().contains("too high"));
+        assert!(check(1000, "5").unwrap_err().contains("too low"));
+        assert_eq!(
+            check(230, "20"),
+            Err("Wait 30 more seconds before submitting again".into())
+        );
+    }
+
+    #[test]
+    fn test_send() {
+        let server = Server::new(vec![response(
+            "200 OK",
+            "",
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let client = || Ok(Client::new(&server.url, "abc"));
+        let dir = env::temp_dir().join(format!("adventofcode-submit-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("submissions.txt");
+        let outcome = send(client, &path, submission(100, Outcome::Wrong, "12"), 0);
+        assert_eq!(outcome, Ok(Outcome::TooLow));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=12"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "100 2024 1 1 too-low 12\n"
+        );
+        // The same answer isn't sent again, as the server would refuse it.
+        let outcome = send(client, &path, submission(200, Outcome::Wrong, "12"), 0);
+        assert!(outcome.unwrap_err().contains("already submitted"));
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fmt::Write as _,
+    fs::{File, OpenOptions},
+    io::{Read, Write},
+    process::{self, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use adventofcode::{
+    animate::{self, Viewer},
+    render::Image,
+};
+
+const HELP: &str = "space: play/pause  n: next step  +/-: faster/slower  s: skip  q: quit";
+
+/// How long each step is shown at first.
+const DELAY: Duration = Duration::from_millis(50);
+
+/// The range of delays that `+` and `-` move between.
+const FASTEST: Duration = Duration::from_millis(1);
+const SLOWEST: Duration = Duration::from_secs(2);
+
+/// How often to check whether the animation has been stopped while waiting
+/// for a key.
+const POLL: Duration = Duration::from_millis(50);
+
+/// The byte that Ctrl-C sends, which the terminal passes on as a key rather
+/// than as a signal, so that it can be put back how it was before quitting.
+const CTRL_C: u8 = 0x03;
+
+/// The exit status for being interrupted by Ctrl-C, as a shell reports it.
+const INTERRUPTED: i32 = 130;
+
+/// Runs `stty` on the terminal, returning what it prints.
+fn stty(args: &[&str]) -> Result<String, String> {
+    let tty = File::open("/dev/tty").map_err(|e| format!("Failed to open the terminal: {e}"))?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(tty)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("Failed to run stty: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("`stty {}` failed", args.join(" ")));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Shows the cursor again and puts back the terminal's `saved` settings.
+fn restore(tty: &mut File, saved: &str) {
+    // There's nothing more to do if this fails.
+    let _ = tty.write_all(b"\x1b[0m\x1b[?25h");
+    let _ = tty.flush();
+    let _ = stty(&[saved]);
+}
+
+/// Animates simulations in the terminal, drawing two rows of cells per
+/// line with half blocks in 24-bit colour, and reading keys straight from
+/// the terminal so that they don't need Enter.
+pub struct Terminal {
+    tty: File,
+    keys: Receiver<u8>,
+    /// The terminal's settings from before, to restore when done.
+    saved: String,
+    delay: Duration,
+    paused: bool,
+    /// The simulation being shown, by target and name.
+    current: Option<(String, String)>,
+    steps: usize,
+    skipping: bool,
+    /// How many lines the last step took up, to draw the next over it.
+    lines: usize,
+}
+
+impl Terminal {
+    /// Takes over the terminal until dropped.
+    pub fn new() -> Result<Self, String> {
+        let saved = stty(&["-g"]).map_err(|e| format!("--animate needs a terminal: {e}"))?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        let tty = OpenOptions::new().read(true).write(true).open("/dev/tty");
+        let tty = tty.map_err(|e| format!("Failed to open the terminal: {e}"))?;
+        let mut reader = tty.try_clone().map_err(|e| e.to_string())?;
+        let (sender, keys) = mpsc::channel();
+        let reader_saved = saved.clone();
+        // The reader blocks until the process exits, as a read can't be
+        // interrupted. It handles Ctrl-C itself, as the solver might not be
+        // showing a step to notice it.
+        thread::spawn(move || {
+            let mut key = [0];
+            while let Ok(1) = reader.read(&mut key) {
+                if key[0] == CTRL_C {
+                    restore(&mut reader, &reader_saved);
+                    process::exit(INTERRUPTED);
+                }
+                if sender.send(key[0]).is_err() {
+                    return;
+                }
+            }
+        });
+        let mut terminal = Self {
+            tty,
+            keys,
+            saved,
+            delay: DELAY,
+            paused: false,
+            current: None,
+            steps: 0,
+            skipping: false,
+            lines: 0,
+        };
+        // Hide the cursor.
+        terminal.write("\x1b[?25l");
+        Ok(terminal)
+    }
+
+    fn write(&mut self, text: &str) {
+        // The animation is only for show, so there's nothing to do about
+        // failing to draw it.
+        let _ = self.tty.write_all(text.as_bytes());
+        let _ = self.tty.flush();
+    }
+
+    fn status(&self) -> String {
+        let (target, name) = self.current.clone().unwrap_or_default();
+        let state = if self.paused { ", paused" } else { "" };
+        format!(
+            "\x1b[0m\x1b[K{target} {name}: step {}, {} ms per step{state}\r\n\x1b[K{HELP}\r\n",
+            self.steps,
+            self.delay.as_millis()
+        )
+    }
+
+    /// Draws `image` and the status over the last step.
+    fn draw(&mut self, image: &Image) {
+        let mut text = String::new();
+        if self.lines > 0 {
+            write!(text, "\x1b[{}A", self.lines).unwrap();
+        }
+        let mut last = None;
+        for y in (0..image.height).step_by(2) {
+            for x in 0..image.width {
+                let top = image.color(x, y);
+                let bottom = if y + 1 < image.height {
+                    image.color(x, y + 1)
+                } else {
+                    [0; 3]
+                };
+                if last != Some((top, bottom)) {
+                    let ([r, g, b], [br, bg, bb]) = (top, bottom);
+                    write!(text, "\x1b[38;2;{r};{g};{b};48;2;{br};{bg};{bb}m").unwrap();
+                    last = Some((top, bottom));
+                }
+                text.push('▀');
+            }
+            text += "\x1b[0m\r\n";
+            last = None;
+        }
+        text += &self.status();
+        self.lines = image.height.div_ceil(2) + 2;
+        self.write(&text);
+    }
+
+    /// Redraws just the status, below the last step.
+    fn redraw_status(&mut self) {
+        let status = self.status();
+        self.write(&format!("\x1b[2A{status}"));
+    }
+}
+
+impl Viewer for Terminal {
+    fn step(&mut self, target: &str, name: &str, image: &Image) -> bool {
+        let simulation = (target.to_string(), name.to_string());
+        if self.current.as_ref() != Some(&simulation) {
+            (self.current, self.steps, self.skipping) = (Some(simulation), 0, false);
+            // Start below anything already on the screen.
+            self.lines = 0;
+        }
+        self.steps += 1;
+        if self.skipping {
+            return true;
+        }
+        self.draw(image);
+        let deadline = Instant::now() + self.delay;
+        loop {
+            let timeout = match self.paused {
+                true => POLL,
+                false => deadline.saturating_duration_since(Instant::now()).min(POLL),
+            };
+            match self.keys.recv_timeout(timeout) {
+                Ok(b' ') => self.paused = !self.paused,
+                Ok(b'n') if self.paused => return true,
+                Ok(b'+' | b'=') => self.delay = (self.delay / 2).max(FASTEST),
+                Ok(b'-' | b'_') => self.delay = (self.delay * 2).min(SLOWEST),
+                Ok(b's') => {
+                    self.skipping = true;
+                    return true;
+                }
+                Ok(b'q') => return false,
+                Ok(_) => continue,
+                // Stop waiting once the runner has finished watching.
+                Err(RecvTimeoutError::Timeout) if !animate::enabled() => return false,
+                Err(RecvTimeoutError::Timeout) if self.paused || Instant::now() < deadline => {
+                    continue
+                }
+                Err(RecvTimeoutError::Timeout) => return true,
+                // Without keys, play the rest.
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.paused = false;
+                    return true;
+                }
+            }
+            self.redraw_status();
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        restore(&mut self.tty, &self.saved);
+    }
+}
@@ -1,86 +1,124 @@
+/// Expands to the input for `$day` of `$year` baked into the binary, if the
+/// `embed-inputs` feature is enabled.
+macro_rules! embedded_input {
+    ($year:tt, $day:tt) => {{
+        #[cfg(feature = "embed-inputs")]
+        let embedded = Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/inputs/",
+            $year,
+            "/",
+            $day,
+            ".txt"
+        )));
+        #[cfg(not(feature = "embed-inputs"))]
+        let embedded = None;
+        embedded
+    }};
+}
+
+/// Expands to the [`Solver`](crate::Solver) for one part of a day, paired
+/// with whether the day has that part.
+macro_rules! solver {
+    ($year:tt, $day:tt, $part:tt) => {
+        paste::paste! {
+            (crate::Solver {
+                year: $year,
+                day: $day,
+                part: $part,
+                embedded: crate::utils::embedded_input!($year, $day),
+                params: <[<year $year>]::[<day $day>]::[<Day $day>] as crate::Solution>::PARAMS,
+                parse: crate::solution::parse::<
+                    [<year $year>]::[<day $day>]::[<Day $day>]
+                >,
+                generate: <[<year $year>]::[<day $day>]::[<Day $day>] as crate::Solution>::generate,
+            }, $part <= <[<year $year>]::[<day $day>]::[<Day $day>] as crate::Solution>::PARTS)
+        }
+    };
+}
+
+/// Declares the module for each year and day, and `SOLVERS` listing each
+/// of the parts that its `Solution` has, from a list like `2024: [1, 2,
+/// 25,]`. Attributes before a year, such as `#[path]`, are applied to its
+/// module.
 macro_rules! make_runner {
     (@helper
         { $($mods:tt)* }
-        { $($labels:tt)* }
-        { $($arms:tt)* }
-        $day:tt,
-        $($rest:tt)*
+        { $($solvers:tt)* }
+        $(#[$attr:meta])* $year:literal { $($year_mods:tt)* }
+        [ $day:tt, $($days:tt)* ]
+        $($years:tt)*
     ) => (
         paste::paste! { crate::utils::make_runner!(@helper
+            { $($mods)* }
             {
-                $($mods)*
-                mod [<day $day>];
+                $($solvers)*
+                crate::utils::solver!($year, $day, 1),
+                crate::utils::solver!($year, $day, 2),
             }
-            {
-                [< Day $day >],
-                $($labels)*
+            $(#[$attr])* $year {
+                $($year_mods)*
+                pub mod [<day $day>];
             }
-            {
-                Task::[< Day $day >] => {
-                    let input = include_str!(concat!("../inputs/", $day, ".txt"));
-                    (stringify!($day), [< day $day >]::solve(input).to_string())
-                },
-                $($arms)*
-            }
-            $($rest)*
+            [ $($days)* ]
+            $($years)*
         ); }
     );
     (@helper
         { $($mods:tt)* }
-        { $($labels:tt)* }
-        { $($arms:tt)* }
-        $day:tt +,
-        $($rest:tt)*
+        { $($solvers:tt)* }
+        $(#[$attr:meta])* $year:literal { $($year_mods:tt)* }
+        []
+        $($years:tt)*
     ) => (
         paste::paste! { crate::utils::make_runner!(@helper
             {
                 $($mods)*
-                mod [<day $day>];
+                #[doc = concat!("Solutions to Advent of Code ", $year, ".")]
+                $(#[$attr])*
+                pub mod [<year $year>] {
+                    $($year_mods)*
+                }
             }
-            {
-                [< Day $day _2 >],
-                [< Day $day >],
-                $($labels)*
-            }
-            {
-                Task::[< Day $day _2 >] => {
-                    let input = include_str!(concat!("../inputs/", $day, ".txt"));
-                    (concat!($day, " (part 2)"), [< day $day >]::solve_2(input).to_string())
-                },
-                Task::[< Day $day >] => {
-                    let input = include_str!(concat!("../inputs/", $day, ".txt"));
-                    (stringify!($day), [< day $day >]::solve(input).to_string())
-                },
-                $($arms)*
-            }
-            $($rest)*
+            { $($solvers)* }
+            $($years)*
         ); }
     );
     (@helper
         { $($mods:tt)* }
-        { $($labels:tt)* }
-        { $lhs:path => $rhs:expr, $($rest_lhs:path => $rest_rhs:expr,)* }
+        { $($solvers:tt)* }
+        $(#[$attr:meta])* $year:literal: [ $($days:tt)* ],
+        $($years:tt)*
+    ) => (
+        crate::utils::make_runner!(@helper
+            { $($mods)* }
+            { $($solvers)* }
+            $(#[$attr])* $year {}
+            [ $($days)* ]
+            $($years)*
+        );
+    );
+    (@helper
+        { $($mods:tt)* }
+        { $($solvers:tt)* }
     ) => (
-        #[derive(clap::ValueEnum, Copy, Clone, Debug)]
-        enum Task { $($labels)* Latest }
-
         $($mods)*
 
-        fn run(args: Args) {
-            let start = std::time::Instant::now();
-            let (day, result) = match args.task {
-                $lhs => $rhs,
-                Task::Latest => $rhs,
-                $($rest_lhs => $rest_rhs,)*
-            };
-            let duration = start.elapsed().as_secs_f32();
-            println!("Computed result for day {day} in {duration:.3} seconds: {result}");
-        }
+        /// A solver for both parts of every day, which `SOLVERS` only keeps
+        /// the parts that exist of.
+        const ALL_SOLVERS: &[(crate::Solver, bool)] = &[$($solvers)*];
+
+        /// Every registered solver, ordered by year, day and then part.
+        pub const SOLVERS: &[crate::Solver] = &crate::solution::existing::<
+            { crate::solution::count_existing(ALL_SOLVERS) },
+        >(ALL_SOLVERS);
     );
 
-    ($($day:tt)*) => {
-        crate::utils::make_runner!(@helper {} {} {} $($day)*);
+    ($($years:tt)*) => {
+        crate::utils::make_runner!(@helper {} {} $($years)*);
     };
 }
 
+pub(crate) use embedded_input;
 pub(crate) use make_runner;
+pub(crate) use solver;
@@ -0,0 +1,218 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use adventofcode::{Answer, Params, Solver};
+use itertools::Itertools;
+
+use crate::{
+    answers,
+    input::{self, InputArgs},
+    report,
+    runner::{self, timed, Failure},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct WatchArgs {
+    /// How often to check for changes, in seconds
+    #[arg(long, value_name = "SECS", default_value_t = 0.5)]
+    interval: f64,
+}
+
+/// The answer for each year, day, input and part, with its check for
+/// samples.
+type Results = BTreeMap<(u32, u32, String, u32), String>;
+
+/// The modification time of each watched file, or `None` if it is missing.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+fn describe(result: &Result<Answer, Failure>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(Failure::Panic(e)) => format!("panicked: {e}"),
+        Err(Failure::Error(e)) => e.clone(),
+        Err(Failure::Parse(e)) => report::parse_error(e),
+        Err(Failure::Timeout) => "timed out".to_string(),
+        Err(Failure::Skipped) => "skipped".to_string(),
+    }
+}
+
+/// The files in the samples directory for `day` of `year`, in order.
+fn sample_files(input: &InputArgs, (year, day): (u32, u32)) -> Vec<PathBuf> {
+    let dir = input.samples_dir(year).join(day.to_string());
+    let entries = fs::read_dir(dir).into_iter().flatten().flatten();
+    entries.map(|entry| entry.path()).sorted().collect()
+}
+
+/// Records when each watched file was last modified: the input for each
+/// day and everything in the day's samples directory.
+fn snapshot(input: &InputArgs, days: &[(u32, u32)]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for &(year, day) in days {
+        let paths = input.path(year, day).into_iter();
+        let paths = paths.chain(sample_files(input, (year, day)));
+        for path in paths {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            snapshot.insert(path, modified);
+        }
+    }
+    snapshot
+}
+
+/// Solves every part in `solvers`, which are all for one day, on `input`
+/// with `params`.
+fn solve(
+    solvers: &[&Solver],
+    input: Result<String, String>,
+    params: Result<Params, String>,
+) -> Vec<Result<Answer, Failure>> {
+    let (input, params) = match input.and_then(|input| Ok((input, params?))) {
+        Ok(loaded) => loaded,
+        Err(e) => return vec![Err(Failure::Error(e)); solvers.len()],
+    };
+    let (parsed, _) = timed(|| (solvers[0].parse)(&input, &params));
+    let answers = match parsed.and_then(|parsed| parsed.map_err(Failure::Parse)) {
+        Ok(parsed) => solvers
+            .iter()
+            .map(|solver| runner::solve(&*parsed, solver).0)
+            .collect(),
+        Err(e) => vec![Err(e); solvers.len()],
+    };
+    answers
+}
+
+/// Runs each day on its input and on each of its samples, checking the
+/// samples' answers where they are known.
+fn run(solvers: &[&'static Solver], input: &InputArgs) -> Results {
+    let mut results = Results::new();
+    for ((year, day), day_solvers) in &solvers.iter().group_by(|solver| (solver.year, solver.day)) {
+        let day_solvers = day_solvers.copied().collect_vec();
+        let params = input.params(day_solvers[0]);
+        let answers = solve(&day_solvers, input.load(year, day, None), params);
+        for (solver, answer) in day_solvers.iter().zip(answers) {
+            let key = (year, day, "input".to_string(), solver.part);
+            results.insert(key, describe(&answer));
+        }
+        let samples = sample_files(input, (year, day));
+        for path in samples
+            .iter()
+            .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let expected = answers::sample_answers(&path.with_extension("answers"));
+            let expected: BTreeMap<_, _> = expected.unwrap_or_default().into_iter().collect();
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read sample from {}: {e}", path.display()));
+            let mut params = Params::new(day_solvers[0].params);
+            let read = input::read_params(&path.with_extension("params"), &mut params);
+            let answers = solve(&day_solvers, text, read.map(|()| params));
+            for (solver, answer) in day_solvers.iter().zip(answers) {
+                let check = match (expected.get(&solver.part), &answer) {
+                    (Some(expected), Ok(answer)) if answer.matches(expected) => {
+                        " (PASS)".to_string()
+                    }
+                    (Some(expected), _) => format!(" (FAIL, expected {expected})"),
+                    (None, _) => String::new(),
+                };
+                let key = (year, day, format!("sample {name}"), solver.part);
+                results.insert(key, describe(&answer) + &check);
+            }
+        }
+    }
+    results
+}
+
+/// Lists the results that differ from `old`, returning them along with the
+/// number that are unchanged.
+fn diff(old: &Results, new: &Results) -> (Vec<String>, usize) {
+    let mut changed = Vec::new();
+    for key in old.keys().chain(new.keys()).sorted().dedup() {
+        let (year, day, name, part) = key;
+        let line = match (old.get(key), new.get(key)) {
+            (Some(old), Some(new)) if old == new => continue,
+            (Some(old), Some(new)) => format!("{old} -> {new}"),
+            (Some(old), None) => format!("{old} -> (removed)"),
+            (None, Some(new)) => new.clone(),
+            (None, None) => unreachable!(),
+        };
+        changed.push(format!("{year} day {day} part {part}, {name}: {line}"));
+    }
+    let unchanged = new
+        .iter()
+        .filter(|(key, new)| old.get(key) == Some(new))
+        .count();
+    (changed, unchanged)
+}
+
+/// Runs the selected solvers on their inputs and samples, then again
+/// whenever one of those files changes, printing the answers that changed.
+/// Polls for changes rather than relying on the OS, and runs until it is
+/// interrupted.
+pub fn watch(
+    solvers: &[&'static Solver],
+    input: &InputArgs,
+    args: &WatchArgs,
+) -> Result<(), String> {
+    let days = solvers
+        .iter()
+        .map(|solver| (solver.year, solver.day))
+        .dedup()
+        .collect_vec();
+    if days
+        .iter()
+        .any(|&(year, day)| input.path(year, day).is_none())
+    {
+        return Err("Can't watch an input read from stdin".to_string());
+    }
+    let interval = Duration::from_secs_f64(args.interval);
+    let mut files = snapshot(input, &days);
+    let mut results = Results::new();
+    loop {
+        let new = run(solvers, input);
+        let (changed, unchanged) = diff(&results, &new);
+        for line in &changed {
+            println!("{line}");
+        }
+        println!("{} changed, {unchanged} unchanged", changed.len());
+        results = new;
+        loop {
+            thread::sleep(interval);
+            let new_files = snapshot(input, &days);
+            if new_files != files {
+                let paths = new_files.keys().chain(files.keys()).unique();
+                let paths = paths.filter(|path| files.get(*path) != new_files.get(*path));
+                println!("\nChanged: {}", paths.map(|p| p.display()).join(", "));
+                files = new_files;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let results = |entries: &[(u32, &str)]| -> Results {
+            let entries = entries
+                .iter()
+                .map(|&(part, answer)| ((2024, 1, "input".to_string(), part), answer.to_string()));
+            entries.collect()
+        };
+        let (changed, unchanged) = diff(
+            &results(&[(1, "3"), (2, "4")]),
+            &results(&[(1, "3"), (2, "5")]),
+        );
+        assert_eq!(changed, ["2024 day 1 part 2, input: 4 -> 5"]);
+        assert_eq!(unchanged, 1);
+        let (changed, unchanged) = diff(&Results::new(), &results(&[(1, "3")]));
+        assert_eq!(changed, ["2024 day 1 part 1, input: 3"]);
+        assert_eq!(unchanged, 0);
+    }
+}
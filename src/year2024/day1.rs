@@ -0,0 +1,67 @@
+use std::iter::zip;
+
+use itertools::Itertools;
+use nom::{
+    character::complete::{digit1, multispace0},
+    combinator::{cut, map, map_res},
+    multi::many1,
+    sequence::{pair, preceded},
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn num(n: &str) -> IResult<&str, u32> {
+    preceded(multispace0, map_res(digit1, str::parse))(n)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = (Vec<u32>, Vec<u32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        error::parse(
+            input,
+            map(many1(pair(num, cut(num))), |pairs| {
+                pairs.into_iter().unzip()
+            }),
+        )
+    }
+
+    /// Generates `size` pairs of location IDs, many of which appear in both
+    /// lists.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        // Keep the IDs small enough that the answers can't overflow.
+        let max = (u32::MAX as u64 / size.max(1) as u64).min(100000);
+        let left = (0..size.max(1)).map(|_| rng.below(max)).collect_vec();
+        let lines = left.iter().map(|l| {
+            let r = match rng.chance(0.3) {
+                true => *rng.choose(&left),
+                false => rng.below(max),
+            };
+            format!("{l}   {r}\n")
+        });
+        Some(Generated::new(lines.collect()))
+    }
+
+    fn part1((l, r): &Self::Parsed<'_>) -> u32 {
+        let (mut l, mut r) = (l.clone(), r.clone());
+        l.sort();
+        r.sort();
+        zip(l, r).map(|(a, b)| a.abs_diff(b)).sum()
+    }
+
+    fn part2((l, r): &Self::Parsed<'_>) -> u32 {
+        let counts = r.iter().counts();
+        l.iter()
+            .map(move |n| n * *counts.get(n).unwrap_or(&0) as u32)
+            .sum()
+    }
+}
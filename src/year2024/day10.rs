@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+type Pos = (i32, i32);
+
+fn find_trails(map: &HashMap<Pos, u32>) -> HashMap<(Pos, Pos), usize> {
+    let mut stack: Vec<_> = map
+        .iter()
+        .filter(|(_, &h)| h == 0)
+        .map(|(&pos, &h)| (pos, pos, h))
+        .collect();
+    let mut trails: HashMap<(Pos, Pos), usize> = HashMap::new();
+    while let Some((start, (y, x), h)) = stack.pop() {
+        if h == 9 {
+            *trails.entry((start, (y, x))).or_default() += 1;
+            continue;
+        }
+        for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (y, x) = (y + dy, x + dx);
+            if map.get(&(y, x)) == Some(&(h + 1)) {
+                stack.push((start, (y, x), h + 1));
+            }
+        }
+    }
+    trails
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = HashMap<Pos, u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut map = HashMap::new();
+        for (y, line) in input.trim().lines().enumerate() {
+            let line = line.trim();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                match c.to_digit(10) {
+                    Some(n) => map.insert((y as i32, x as i32), n),
+                    None if c == '.' => None,
+                    None => return Err(Error::at(input, &line[i..], "a height or `.`")),
+                };
+            }
+        }
+        Ok(map)
+    }
+
+    /// Generates a map `size` tiles square, with hiking trails laid over
+    /// random heights.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(1) as i64;
+        let mut map = HashMap::new();
+        for y in 0..size {
+            for x in 0..size {
+                map.insert((y, x), rng.range(0..10));
+            }
+        }
+        for _ in 0..size {
+            let mut pos = (rng.range(0..size), rng.range(0..size));
+            for h in 0..10 {
+                map.insert(pos, h);
+                let &(dy, dx) = rng.choose(&[(-1, 0), (1, 0), (0, -1), (0, 1)]);
+                pos = (
+                    (pos.0 + dy).clamp(0, size - 1),
+                    (pos.1 + dx).clamp(0, size - 1),
+                );
+            }
+        }
+        let rows = (0..size).map(|y| {
+            let row = (0..size).map(|x| char::from_digit(map[&(y, x)] as u32, 10).unwrap());
+            row.chain(['\n']).collect::<String>()
+        });
+        Some(Generated::new(rows.collect()))
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> usize {
+        find_trails(map).len()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> usize {
+        find_trails(map).values().sum()
+    }
+}
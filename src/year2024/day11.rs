@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Error,
+};
+
+fn blink(stone: u64) -> Vec<u64> {
+    if stone == 0 {
+        return vec![1];
+    }
+    let stone_str = stone.to_string();
+    if stone_str.len().is_multiple_of(2) {
+        let (s1, s2) = stone_str.split_at(stone_str.len() / 2);
+        let (s1, s2) = (s1.parse().unwrap(), s2.parse().unwrap());
+        return vec![s1, s2];
+    }
+    vec![stone * 2024]
+}
+
+pub fn compute(stones: &[u64], blinks: i64) -> usize {
+    let mut memo = HashMap::new();
+    let mut counts = stones.iter().copied().counts();
+    for _ in 0..blinks {
+        let mut new_counts = HashMap::new();
+        for (stone, n) in counts {
+            for stone in memo.entry(stone).or_insert_with(|| blink(stone)) {
+                *new_counts.entry(*stone).or_default() += n;
+            }
+        }
+        counts = new_counts;
+    }
+    counts.values().sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = (Vec<u64>, Params);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "blinks_1",
+            default: 25,
+            min: 0,
+            max: 100,
+        },
+        Param {
+            name: "blinks_2",
+            default: 75,
+            min: 0,
+            max: 100,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        let stones = input
+            .split_ascii_whitespace()
+            .map(|n| n.parse().map_err(|_| Error::at(input, n, "a number")))
+            .collect::<Result<_, _>>()?;
+        Ok((stones, params.clone()))
+    }
+
+    /// Generates `size` stones.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut stones = (0..size.max(1)).map(|_| rng.below(1_000_000));
+        Some(Generated::new(stones.join(" ") + "\n"))
+    }
+
+    fn part1((stones, params): &Self::Parsed<'_>) -> usize {
+        compute(stones, params.get("blinks_1"))
+    }
+
+    fn part2((stones, params): &Self::Parsed<'_>) -> usize {
+        compute(stones, params.get("blinks_2"))
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::iproduct;
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn find_regions(map: &HashMap<(i32, i32), char>) -> Vec<HashSet<(i32, i32)>> {
+    let mut stack: Vec<_> = map.keys().copied().enumerate().collect();
+    let mut seen = HashSet::new();
+    let mut regions: HashMap<usize, HashSet<(i32, i32)>> = HashMap::new();
+    while let Some((r, (y, x))) = stack.pop() {
+        if !seen.insert((y, x)) {
+            continue;
+        }
+        regions.entry(r).or_default().insert((y, x));
+        let c = map.get(&(y, x));
+        for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (y, x) = (y + dy, x + dx);
+            if map.get(&(y, x)) == c {
+                stack.push((r, (y, x)));
+            }
+        }
+    }
+    regions.into_values().collect()
+}
+
+fn count_perimeter(region: HashSet<(i32, i32)>) -> usize {
+    region
+        .iter()
+        .flat_map(|(y, x)| [(-1, 0), (1, 0), (0, -1), (0, 1)].map(|(dy, dx)| (y + dy, x + dx)))
+        .filter(|p| !region.contains(p))
+        .count()
+}
+
+fn count_sides(region: HashSet<(i32, i32)>) -> usize {
+    region
+        .iter()
+        .flat_map(|(y, x)| {
+            iproduct!([-1, 1], [-1, 1]).map(|(dy, dx)| {
+                (
+                    region.contains(&(*y + dy, *x + dx)),
+                    region.contains(&(*y, *x + dx)),
+                    region.contains(&(*y + dy, *x)),
+                )
+            })
+        })
+        .filter(|&(a, b, c)| !b && !c || !a && b && c)
+        .count()
+}
+
+/// Counts the sides of a region from its pieces of fence: each piece starts
+/// a new side unless the piece next to it along the side is fenced too.
+fn count_sides_slowly(region: &HashSet<(i32, i32)>) -> usize {
+    let fenced = |(y, x): (i32, i32), (dy, dx): (i32, i32)| {
+        region.contains(&(y, x)) && !region.contains(&(y + dy, x + dx))
+    };
+    iproduct!(region.iter(), [(-1, 0), (1, 0), (0, -1), (0, 1)])
+        .filter(|&(&(y, x), (dy, dx))| {
+            fenced((y, x), (dy, dx)) && !fenced((y + dx, x + dy), (dy, dx))
+        })
+        .count()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = HashMap<(i32, i32), char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut map = HashMap::new();
+        for (y, line) in input.trim().lines().enumerate() {
+            for (x, c) in line.trim().chars().enumerate() {
+                map.insert((y as i32, x as i32), c);
+            }
+        }
+        Ok(map)
+    }
+
+    /// Generates a garden `size` plots square, with regions of many shapes.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(1);
+        let mut rows: Vec<Vec<char>> = Vec::new();
+        for y in 0..size {
+            let mut row = Vec::new();
+            for x in 0..size {
+                // Mostly continue the region to the left or above.
+                let plant = match rng.below(10) {
+                    0..=3 if x > 0 => row[x - 1],
+                    4..=7 if y > 0 => rows[y - 1][x],
+                    _ => (b'A' + rng.below(26) as u8) as char,
+                };
+                row.push(plant);
+            }
+            rows.push(row);
+        }
+        let rows = rows.into_iter().map(|row| row.into_iter().chain(['\n']));
+        Some(Generated::new(rows.flatten().collect()))
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> usize {
+        find_regions(map)
+            .into_iter()
+            .map(|region| region.len() * count_perimeter(region))
+            .sum()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> usize {
+        find_regions(map)
+            .into_iter()
+            .map(|region| region.len() * count_sides(region))
+            .sum()
+    }
+
+    fn reference2(map: &Self::Parsed<'_>) -> Option<usize> {
+        let regions = find_regions(map).into_iter();
+        Some(
+            regions
+                .map(|region| region.len() * count_sides_slowly(&region))
+                .sum(),
+        )
+    }
+}
@@ -0,0 +1,134 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0},
+    combinator::{cut, map_res},
+    error::context,
+    multi::many1,
+    sequence::{pair, preceded, tuple},
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+type Machine = ((i64, i64), (i64, i64), (i64, i64));
+
+trait Compute {
+    fn compute(self) -> i64;
+}
+
+impl<T> Compute for T
+where
+    T: Iterator<Item = Machine>,
+{
+    fn compute(self) -> i64 {
+        self.filter_map(|(a, b, p)| {
+            let s_num: i64 = p.0 * a.1 - p.1 * a.0;
+            let s_denom = b.0 * a.1 - b.1 * a.0;
+            if s_num % s_denom != 0 {
+                return None;
+            }
+            let s = s_num / s_denom;
+            let t_num = p.1 - s * b.1;
+            if t_num % a.1 != 0 {
+                return None;
+            }
+            let t = t_num / a.1;
+            Some((s, t))
+        })
+        .map(|(s, t)| s + 3 * t)
+        .sum()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Machine>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        fn num(input: &str) -> IResult<&str, i64> {
+            map_res(digit1, str::parse)(input)
+        }
+        let button_a = context(
+            "a line like `Button A: X+94, Y+34`",
+            pair(
+                preceded(tag("Button A: X+"), num),
+                preceded(tag(", Y+"), num),
+            ),
+        );
+        let button_b = context(
+            "a line like `Button B: X+22, Y+67`",
+            pair(
+                preceded(tag("Button B: X+"), num),
+                preceded(tag(", Y+"), num),
+            ),
+        );
+        let prize = context(
+            "a line like `Prize: X=8400, Y=5400`",
+            pair(preceded(tag("Prize: X="), num), preceded(tag(", Y="), num)),
+        );
+        // Once a machine has started, the rest of it must follow.
+        let machine = tuple((
+            preceded(multispace0, button_a),
+            cut(preceded(multispace0, button_b)),
+            cut(preceded(multispace0, prize)),
+        ));
+        error::parse(input, many1(machine))
+    }
+
+    /// Generates `size` claw machines, about half of which can win a prize.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut machines = Vec::new();
+        while machines.len() < size.max(1) {
+            let a = (rng.range(10..100), rng.range(10..100));
+            let b = (rng.range(10..100), rng.range(10..100));
+            // The buttons can't move the claw in the same direction.
+            if a.0 * b.1 == a.1 * b.0 {
+                continue;
+            }
+            let (s, t) = (rng.range(0..100), rng.range(0..100));
+            let mut prize = (s * a.0 + t * b.0, s * a.1 + t * b.1);
+            if rng.chance(0.5) {
+                prize.0 += rng.range(1..10);
+            }
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        Some(Generated::new(machines.join("\n")))
+    }
+
+    fn part1(machines: &Self::Parsed<'_>) -> i64 {
+        machines.iter().copied().compute()
+    }
+
+    fn part2(machines: &Self::Parsed<'_>) -> i64 {
+        machines
+            .iter()
+            .map(|&(a, b, p)| {
+                let p = (p.0 + 10000000000000, p.1 + 10000000000000);
+                (a, b, p)
+            })
+            .compute()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_missing_button() {
+        let input = "Button A: X+94, Y+34\nPrize: X=8400, Y=5400\n";
+        let error = Day13::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (2, 1, Some('P')));
+        assert_eq!(error.expected, "a line like `Button B: X+22, Y+67`");
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    cmp::Ordering::{Equal, Greater, Less},
+    collections::HashSet,
+    iter::zip,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0, space0},
+    combinator::{cut, map, map_res, opt, recognize},
+    error::context,
+    multi::many1,
+    sequence::{pair, preceded, separated_pair},
+};
+
+use crate::{
+    animate::step,
+    cancel,
+    error::{self, IResult},
+    log::debug,
+    render::{frame, Image, Palette},
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Answer, Error,
+};
+
+type Vec2 = (i64, i64);
+
+fn tick(size: (i64, i64), n: i64, pos: &mut [(i64, i64)], vel: &[(i64, i64)]) {
+    for (p, v) in zip(pos, vel) {
+        p.0 = (p.0 + v.0 * n).rem_euclid(size.0);
+        p.1 = (p.1 + v.1 * n).rem_euclid(size.1);
+    }
+}
+
+fn count(size: (i64, i64), pos: &[(i64, i64)]) -> i64 {
+    let mut quads = [[0; 2]; 2];
+    for p in pos {
+        let x = match (p.0 * 2).cmp(&(size.0 - 1)) {
+            Less => 0,
+            Greater => 1,
+            Equal => continue,
+        };
+        let y = match (p.1 * 2).cmp(&(size.1 - 1)) {
+            Less => 0,
+            Greater => 1,
+            Equal => continue,
+        };
+        quads[x][y] += 1;
+    }
+    quads.into_iter().flatten().product()
+}
+
+const PALETTE: Palette = &[('.', [16, 32, 16]), ('#', [96, 224, 96])];
+
+/// A picture of the robots, with a `#` wherever there is one.
+fn picture(size: (i64, i64), pos: &[(i64, i64)]) -> Vec<String> {
+    let pos: HashSet<_> = pos.iter().collect();
+    let tile = |x, y| if pos.contains(&(x, y)) { '#' } else { '.' };
+    (0..size.1)
+        .map(|y| (0..size.0).map(|x| tile(x, y)).collect())
+        .collect()
+}
+
+fn score(size: (i64, i64), pos: &[(i64, i64)]) -> i64 {
+    pos.iter()
+        .map(|p| {
+            let a = (p.0 - size.0 / 2).pow(2) as f64;
+            let b = (p.1 - size.1 / 2).pow(2) as f64;
+            (a + b).sqrt()
+        })
+        .sum::<f64>() as i64
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = (Vec<Vec2>, Vec<Vec2>, Params);
+    type Answer1 = i64;
+    type Answer2 = Answer;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "width",
+            default: 101,
+            min: 1,
+            max: 10_000,
+        },
+        Param {
+            name: "height",
+            default: 103,
+            min: 1,
+            max: 10_000,
+        },
+        // The time after which part 1 counts the robots.
+        Param {
+            name: "seconds",
+            default: 100,
+            min: 0,
+            max: 1_000_000_000,
+        },
+        // How many seconds part 2 searches for the picture.
+        Param {
+            name: "frames",
+            default: 10000,
+            min: 1,
+            max: 1_000_000,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        fn num(input: &str) -> IResult<&str, i64> {
+            map_res(recognize(pair(opt(tag("-")), digit1)), str::parse)(input)
+        }
+        let pos = preceded(tag("p="), cut(separated_pair(num, tag(","), num)));
+        let vel = preceded(tag("v="), separated_pair(num, tag(","), num));
+        let robot = separated_pair(pos, space0, cut(vel));
+        let line = preceded(multispace0, context("a robot like `p=0,4 v=3,-3`", robot));
+        let robots = map(many1(line), |robots| robots.into_iter().unzip());
+        let (pos, vel) = error::parse(input, robots)?;
+        Ok((pos, vel, params.clone()))
+    }
+
+    /// Generates `size` robots in a space of the default size.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let params = Params::new(Self::PARAMS);
+        let (width, height) = (params.get("width"), params.get("height"));
+        let robots = (0..size.max(1)).map(|_| {
+            let (x, y) = (rng.range(0..width), rng.range(0..height));
+            let (dx, dy) = (rng.range(-width + 1..width), rng.range(-height + 1..height));
+            format!("p={x},{y} v={dx},{dy}\n")
+        });
+        Some(Generated::new(robots.collect()))
+    }
+
+    fn part1((pos, vel, params): &Self::Parsed<'_>) -> i64 {
+        let size = (params.get("width"), params.get("height"));
+        let mut pos = pos.clone();
+        tick(size, params.get("seconds"), &mut pos, vel);
+        count(size, &pos)
+    }
+
+    /// The time when the robots are closest together, which is when they
+    /// make the picture, shown along with it.
+    fn part2((pos, vel, params): &Self::Parsed<'_>) -> Answer {
+        let size = (params.get("width"), params.get("height"));
+        let (score, n, pos) = (0..params.get("frames"))
+            .map(|n| {
+                cancel::check();
+                let mut pos = pos.clone();
+                tick(size, n, &mut pos, vel);
+                step!("robots", Image::from_rows(&picture(size, &pos), PALETTE));
+                (score(size, &pos), n, pos)
+            })
+            .min()
+            .unwrap();
+        debug!("The robots are closest together after {n} seconds, scoring {score}");
+        let picture = picture(size, &pos);
+        frame!("picture", Image::from_rows(&picture, PALETTE));
+        Answer::Grid(Box::new(n.into()), picture)
+    }
+}
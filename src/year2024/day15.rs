@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    animate::step,
+    render::{frame, Image, Palette},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+type Pos = (i32, i32);
+
+const PALETTE: Palette = &[
+    ('.', [24, 24, 32]),
+    ('#', [112, 112, 128]),
+    ('O', [192, 144, 80]),
+    ('@', [224, 64, 64]),
+];
+
+#[derive(Default)]
+struct Map {
+    walls: HashSet<Pos>,
+    box_ids: HashMap<Pos, usize>,
+    box_positions: Vec<Vec<Pos>>,
+    robot: Pos,
+}
+
+impl Map {
+    fn step(&mut self, (dy, dx): Pos) {
+        let first_pos = (self.robot.0 + dy, self.robot.1 + dx);
+        let mut move_ids = HashSet::new();
+        let mut stack = vec![first_pos];
+        while let Some((y, x)) = stack.pop() {
+            if self.walls.contains(&(y, x)) {
+                return;
+            }
+            if let Some(&id) = self.box_ids.get(&(y, x)) {
+                if move_ids.insert(id) {
+                    stack.extend(self.box_positions[id].iter().map(|(y, x)| (y + dy, x + dx)));
+                }
+            }
+        }
+        for &id in &move_ids {
+            for (y, x) in &mut self.box_positions[id] {
+                self.box_ids.remove(&(*y, *x));
+                (*y, *x) = (*y + dy, *x + dx);
+            }
+        }
+        for id in move_ids {
+            for &pos in &self.box_positions[id] {
+                self.box_ids.insert(pos, id);
+            }
+        }
+        self.robot = first_pos;
+    }
+
+    fn image(&self) -> Image {
+        let height = self
+            .walls
+            .iter()
+            .map(|&(y, _)| y as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let width = self
+            .walls
+            .iter()
+            .map(|&(_, x)| x as usize + 1)
+            .max()
+            .unwrap_or(0);
+        Image::from_fn(width, height, PALETTE, |x, y| {
+            let pos = (y as i32, x as i32);
+            if pos == self.robot {
+                '@'
+            } else if self.walls.contains(&pos) {
+                '#'
+            } else if self.box_ids.contains_key(&pos) {
+                'O'
+            } else {
+                '.'
+            }
+        })
+    }
+
+    fn gps(&self) -> i32 {
+        self.box_positions
+            .iter()
+            .flat_map(|positions| positions.iter().min())
+            .map(|&(y, x)| 100 * y + x)
+            .sum()
+    }
+}
+
+fn compute<const P2: bool>((cells, dirs): &(Vec<(Pos, char)>, Vec<Pos>)) -> i32 {
+    let mut map = Map::default();
+    for &((y, x), c) in cells {
+        let positions = if P2 {
+            vec![(y, 2 * x), (y, 1 + 2 * x)]
+        } else {
+            vec![(y, x)]
+        };
+        match c {
+            '@' => map.robot = positions.into_iter().min().unwrap(),
+            '#' => {
+                map.walls.extend(positions);
+            }
+            'O' => {
+                let id = map.box_positions.len();
+                map.box_ids.extend(positions.iter().map(|&pos| (pos, id)));
+                map.box_positions.push(positions);
+            }
+            _ => (),
+        }
+    }
+    let name = if P2 { "wide" } else { "narrow" };
+    for (i, &dir) in dirs.iter().enumerate() {
+        if i % (dirs.len() / 100).max(1) == 0 {
+            frame!(name, map.image());
+        }
+        step!(name, map.image());
+        map.step(dir);
+    }
+    frame!(name, map.image());
+    step!(name, map.image());
+    map.gps()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = (Vec<(Pos, char)>, Vec<Pos>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut cells = Vec::new();
+        let mut lines = input.trim().lines().enumerate();
+        for (y, line) in lines.by_ref() {
+            let line = line.trim();
+            if line.is_empty() {
+                break;
+            }
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if !"#.O@".contains(c) {
+                    return Err(Error::at(input, &line[i..], "`#`, `.`, `O` or `@`"));
+                }
+                cells.push(((y as i32, x as i32), c));
+            }
+        }
+        let mut dirs = Vec::new();
+        for (_, line) in lines {
+            let line = line.trim();
+            for (i, c) in line.char_indices() {
+                dirs.push(match c {
+                    '<' => (0, -1),
+                    '^' => (-1, 0),
+                    '>' => (0, 1),
+                    'v' => (1, 0),
+                    _ => return Err(Error::at(input, &line[i..], "a move `<`, `^`, `>` or `v`")),
+                });
+            }
+        }
+        Ok((cells, dirs))
+    }
+
+    /// Generates a warehouse `size` tiles square, and enough moves for the
+    /// robot to cross it many times.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(3);
+        let robot = (rng.range(1..size as i64 - 1), rng.range(1..size as i64 - 1));
+        let mut input = String::new();
+        for y in 0..size as i64 {
+            for x in 0..size as i64 {
+                input.push(match rng.below(20) {
+                    _ if (y, x) == robot => '@',
+                    _ if y == 0 || x == 0 || y == size as i64 - 1 || x == size as i64 - 1 => '#',
+                    0 => '#',
+                    1..=6 => 'O',
+                    _ => '.',
+                });
+            }
+            input.push('\n');
+        }
+        for _ in 0..8 * size {
+            input.push('\n');
+            input.extend((0..size).map(|_| *rng.choose(&['<', '^', '>', 'v'])));
+        }
+        Some(Generated::new(input + "\n"))
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> i32 {
+        compute::<false>(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> i32 {
+        compute::<true>(parsed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unexpected_move() {
+        let error = Day15::parse("#@.#\n\n<>x").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (3, 3, Some('x')));
+    }
+}
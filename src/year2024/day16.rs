@@ -0,0 +1,185 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use itertools::{iproduct, Itertools};
+
+use crate::{
+    cancel,
+    render::{frame, Image, Palette},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+struct State {
+    pos: (i32, i32),
+    dir: (i32, i32),
+}
+
+#[derive(Default)]
+pub struct Maze {
+    start: State,
+    end: (i32, i32),
+    walls: HashSet<(i32, i32)>,
+}
+
+fn dijkstra(
+    walls: &HashSet<(i32, i32)>,
+    starts: impl IntoIterator<Item = State>,
+) -> HashMap<State, usize> {
+    let mut queue: BinaryHeap<_> = starts.into_iter().map(|s| Reverse((0, s))).collect();
+    let mut distances = HashMap::new();
+    while let Some(Reverse((score, state))) = queue.pop() {
+        cancel::check();
+        if walls.contains(&state.pos) || distances.contains_key(&state) {
+            continue;
+        }
+        distances.insert(state, score);
+        let State {
+            pos: (y, x),
+            dir: (dy, dx),
+        } = state;
+        for (score, pos, dir) in [
+            (score + 1, (y + dy, x + dx), state.dir),
+            (score + 1000, state.pos, (dx, -dy)),
+            (score + 1000, state.pos, (-dx, dy)),
+        ] {
+            queue.push(Reverse((score, State { pos, dir })));
+        }
+    }
+    distances
+}
+
+/// The lowest score of any path to the end, given the scores of every state
+/// from the start.
+fn best_score(maze: &Maze, from_start: &HashMap<State, usize>) -> usize {
+    from_start
+        .iter()
+        .filter_map(|(state, &score)| (state.pos == maze.end).then_some(score))
+        .min()
+        .unwrap()
+}
+
+const PALETTE: Palette = &[
+    ('.', [24, 24, 32]),
+    ('#', [112, 112, 128]),
+    ('O', [96, 192, 224]),
+    ('S', [96, 224, 96]),
+    ('E', [224, 64, 64]),
+];
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut maze = Maze::default();
+        let (mut start, mut end) = (None, None);
+        for (y, line) in input.trim().lines().enumerate() {
+            let line = line.trim();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let pos = (y as i32, x as i32);
+                match c {
+                    'S' => start = Some(pos),
+                    'E' => end = Some(pos),
+                    '#' => {
+                        maze.walls.insert(pos);
+                    }
+                    '.' => (),
+                    _ => return Err(Error::at(input, &line[i..], "`.`, `#`, `S` or `E`")),
+                }
+            }
+        }
+        let start = start.ok_or_else(|| Error::missing(input, "a start tile `S`"))?;
+        maze.start = State {
+            pos: start,
+            dir: (0, 1),
+        };
+        maze.end = end.ok_or_else(|| Error::missing(input, "an end tile `E`"))?;
+        Ok(maze)
+    }
+
+    /// Generates a maze `size` tiles square, with some loops so that there
+    /// can be several best paths.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        // The maze's cells have odd coordinates, with walls between them.
+        let size = (size.max(5) - 1) / 2 * 2 + 1;
+        let mut grid = vec![vec!['#'; size]; size];
+        let start = (size - 2, 1);
+        let mut stack = vec![start];
+        grid[start.0][start.1] = '.';
+        while let Some(&(y, x)) = stack.last() {
+            let mut next = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+                .map(|(dy, dx)| ((y as i64 + dy) as usize, (x as i64 + dx) as usize))
+                .into_iter()
+                .filter(|&(y, x)| y < size && x < size && grid[y][x] == '#')
+                .collect_vec();
+            if next.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut next);
+            let (ny, nx) = next[0];
+            grid[(y + ny) / 2][(x + nx) / 2] = '.';
+            grid[ny][nx] = '.';
+            stack.push((ny, nx));
+        }
+        for (y, x) in iproduct!(1..size - 1, 1..size - 1) {
+            if (y + x) % 2 == 1 && rng.chance(0.1) {
+                grid[y][x] = '.';
+            }
+        }
+        grid[start.0][start.1] = 'S';
+        grid[1][size - 2] = 'E';
+        let rows = grid.into_iter().map(|row| row.into_iter().chain(['\n']));
+        Some(Generated::new(rows.flatten().collect()))
+    }
+
+    fn part1(maze: &Self::Parsed<'_>) -> usize {
+        best_score(maze, &dijkstra(&maze.walls, [maze.start]))
+    }
+
+    fn part2(maze: &Self::Parsed<'_>) -> usize {
+        let from_start = dijkstra(&maze.walls, [maze.start]);
+        let best_score = best_score(maze, &from_start);
+        let ends = [(-1, 0), (1, 0), (0, -1), (0, 1)].map(|dir| State { pos: maze.end, dir });
+        let from_end = dijkstra(&maze.walls, ends);
+        let tiles: HashSet<_> = from_start
+            .into_iter()
+            .filter_map(|(mut state, score_1)| {
+                // State -> End == End -> reversed(State)
+                state.dir = (-state.dir.0, -state.dir.1);
+                let score_2 = from_end.get(&state)?;
+                (score_1 + score_2 == best_score).then_some(state.pos)
+            })
+            .collect();
+        frame!("best-paths", {
+            let height = maze
+                .walls
+                .iter()
+                .map(|&(y, _)| y as usize + 1)
+                .max()
+                .unwrap_or(0);
+            let width = maze
+                .walls
+                .iter()
+                .map(|&(_, x)| x as usize + 1)
+                .max()
+                .unwrap_or(0);
+            Image::from_fn(width, height, PALETTE, |x, y| match (y as i32, x as i32) {
+                pos if pos == maze.start.pos => 'S',
+                pos if pos == maze.end => 'E',
+                pos if maze.walls.contains(&pos) => '#',
+                pos if tiles.contains(&pos) => 'O',
+                _ => '.',
+            })
+        });
+        tiles.len()
+    }
+}
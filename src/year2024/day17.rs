@@ -0,0 +1,235 @@
+use std::{collections::VecDeque, iter::from_fn, rc::Rc};
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0, space0},
+    combinator::{cut, map_res, verify},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+#[derive(Clone, Debug)]
+/// The 3-bit computer, loaded with a program and its initial registers.
+pub struct VM {
+    ip: usize,
+    reg: [u64; 3],
+    mem: Rc<[u64]>,
+}
+
+impl VM {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        fn num(input: &str) -> IResult<&str, u64> {
+            preceded(space0, map_res(digit1, str::parse))(input)
+        }
+        fn register<'a>(
+            name: &'static str,
+            example: &'static str,
+        ) -> impl FnMut(&'a str) -> IResult<&'a str, u64> {
+            preceded(multispace0, context(example, preceded(tag(name), num)))
+        }
+        let value = context("a 3-bit number", verify(num, |&n| n < 8));
+        let program = context(
+            "a line like `Program: 0,1,5,4,3,0`",
+            preceded(tag("Program:"), separated_list1(tag(","), cut(value))),
+        );
+        let (a, b, c, mem) = error::parse(
+            input,
+            tuple((
+                register("Register A:", "a line like `Register A: 729`"),
+                register("Register B:", "a line like `Register B: 0`"),
+                register("Register C:", "a line like `Register C: 0`"),
+                preceded(multispace0, program),
+            )),
+        )?;
+        Ok(Self {
+            ip: 0,
+            reg: [a, b, c],
+            mem: mem.into(),
+        })
+    }
+
+    fn last_combo(&mut self) -> u64 {
+        match *self.mem.get(self.ip - 1).unwrap() {
+            i @ 0..=3 => i,
+            i @ 4..=6 => self.reg[i as usize - 4],
+            _ => panic!(),
+        }
+    }
+
+    fn last_literal(&mut self) -> u64 {
+        *self.mem.get(self.ip - 1).unwrap()
+    }
+
+    /// A divided by two to the power of the last combo operand, which is
+    /// zero once that is at least 64.
+    fn divide(&mut self) -> u64 {
+        let shift = self.last_combo().try_into().unwrap_or(u32::MAX);
+        self.reg[0].checked_shr(shift).unwrap_or(0)
+    }
+
+    pub fn with_a(mut self, a: u64) -> Self {
+        self.reg[0] = a;
+        self
+    }
+
+    /// Runs the program until it halts, yielding each output value.
+    pub fn run(mut self) -> impl Iterator<Item = u64> {
+        from_fn(move || loop {
+            let &op = self.mem.get(self.ip)?;
+            self.ip += 2;
+            match op {
+                1 => self.reg[1] ^= self.last_literal(),
+                2 => self.reg[1] = self.last_combo() & 0x07,
+                3 if self.reg[0] == 0 => (),
+                3 => self.ip = self.last_literal() as usize,
+                4 => self.reg[1] ^= self.reg[2],
+                5 => return Some(self.last_combo() & 0x07),
+                6 => self.reg[1] = self.divide(),
+                7 => self.reg[2] = self.divide(),
+                0 => self.reg[0] = self.divide(),
+                _ => panic!(),
+            }
+        })
+    }
+}
+
+/// The lowest value of register A that makes the program output itself,
+/// found a few bits at a time, starting from the last output. This relies
+/// on the program being a loop that shifts A right by a fixed number of
+/// bits on each pass, setting B and C from A before it reads them, so that
+/// the last outputs only depend on the highest bits of A.
+fn find_quine(vm: &VM) -> Option<u64> {
+    let shift = vm.mem.chunks(2).find_map(|inst| match inst {
+        &[0, shift @ 1..=3] => Some(shift),
+        _ => None,
+    })?;
+    let mut queue: VecDeque<(u64, usize)> = [(0, vm.mem.len())].into();
+    while let Some((a, len)) = queue.pop_front() {
+        if len == 0 {
+            return Some(a);
+        }
+        for n in 0..1 << shift {
+            let a = (a << shift) + n;
+            // A leading zero would leave A too short to output the program.
+            let rest = vm.mem[len - 1..].iter().copied();
+            if a != 0 && vm.clone().with_a(a).run().eq(rest) {
+                queue.push_back((a, len - 1));
+            }
+        }
+    }
+    None
+}
+
+/// A random program shaped like the puzzle's, a loop that shifts A right by
+/// one to three bits on each pass, and outputs A or sets B, and maybe C,
+/// from A and outputs one of them. Where it shifts A varies, and a `long`
+/// program mixes more into B and C.
+fn random_program(rng: &mut Rng, long: bool) -> Vec<u64> {
+    let mut body = vec![];
+    let out = match rng.below(if long { 3 } else { 2 }) {
+        0 => 4,
+        n => {
+            // B is A's lowest bits or a shift of A, then maybe mixed with a
+            // shift of A in C.
+            body.push(*rng.choose(&[[2, 4], [6, 1], [6, 2]]));
+            if long && rng.chance(0.5) {
+                body.push([1, rng.below(8)]);
+            }
+            if n == 2 {
+                body.push([7, 5]);
+                let (op, operand) = (*rng.choose(&[1, 4]), rng.below(8));
+                body.push([op, operand]);
+            }
+            *rng.choose(&[5, 5, 6][..n as usize + 1])
+        }
+    };
+    body.push([5, out]);
+    let shift = rng.range(1..4) as u64;
+    body.insert(rng.below(body.len() as u64 + 1) as usize, [0, shift]);
+    body.push([3, 0]);
+    body.concat()
+}
+
+/// The longest program that `reference2` searches for an answer for, and
+/// the values of register A that it tries.
+const SEARCH_LEN: usize = 8;
+const SEARCH_LIMIT: u64 = 1 << 17;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = VM;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        VM::new(input)
+    }
+
+    /// Generates a program from `random_program` that has an answer to
+    /// part 2, short enough for `reference2` at sizes up to 8, starting with
+    /// `size` octal digits in register A.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let a = (0..size.clamp(1, 21)).fold(rng.range(1..8) as u64, |a, _| a * 8 + rng.below(8));
+        loop {
+            let vm = VM {
+                ip: 0,
+                reg: [a, 0, 0],
+                mem: random_program(rng, size > 8).into(),
+            };
+            // Try again unless part 2 has an answer.
+            if find_quine(&vm).is_some() {
+                let input = format!(
+                    "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                    vm.mem.iter().join(",")
+                );
+                return Some(Generated::new(input));
+            }
+        }
+    }
+
+    fn part1(vm: &Self::Parsed<'_>) -> String {
+        vm.clone().run().join(",")
+    }
+
+    fn part2(vm: &Self::Parsed<'_>) -> u64 {
+        find_quine(vm).expect("No value of register A makes the program output itself")
+    }
+
+    /// Tries every value of register A in turn, up to a limit, which makes
+    /// no assumptions about the program but only finds the small answers of
+    /// short programs.
+    fn reference2(vm: &Self::Parsed<'_>) -> Option<u64> {
+        if vm.mem.len() > SEARCH_LEN {
+            return None;
+        }
+        let program = vm.mem.iter().copied();
+        (1..SEARCH_LIMIT).find(|&a| {
+            // Stop early on output longer than the program.
+            let output = vm.clone().with_a(a).run().take(vm.mem.len() + 1);
+            output.eq(program.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bad_instruction() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4\n";
+        let error = Day17::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (5, 14, Some('8')));
+        assert_eq!(error.expected, "a 3-bit number");
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    ops::RangeBounds,
+};
+
+use itertools::{iproduct, Itertools};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0},
+    combinator::{cut, map_res},
+    error::context,
+    multi::many1,
+    sequence::{preceded, separated_pair},
+};
+
+use crate::{
+    cancel,
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Error,
+};
+
+fn path(
+    x_range: impl RangeBounds<i32>,
+    y_range: impl RangeBounds<i32>,
+    bytes: &[(i32, i32)],
+) -> Option<usize> {
+    let map: HashSet<_> = bytes.iter().collect();
+    let mut queue: VecDeque<_> = [(0, (0, 0))].into();
+    let mut seen = HashSet::new();
+    while let Some((steps, (x, y))) = queue.pop_front() {
+        if !x_range.contains(&x)
+            || !y_range.contains(&y)
+            || map.contains(&(x, y))
+            || !seen.insert((x, y))
+        {
+            continue;
+        }
+        if !x_range.contains(&(x + 1)) && !y_range.contains(&(y + 1)) {
+            return Some(steps);
+        }
+        for (dy, dx) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            queue.push_back((steps + 1, (x + dx, y + dy)));
+        }
+    }
+    None
+}
+
+/// The first byte to cut off the exit, if any does.
+fn compute_2(
+    x_range: impl RangeBounds<i32> + Clone,
+    y_range: impl RangeBounds<i32> + Clone,
+    bytes: &[(i32, i32)],
+) -> Option<(i32, i32)> {
+    let (mut l, mut r) = (0, bytes.len());
+    while l < r {
+        cancel::check();
+        let mid = l + (r - l) / 2;
+        if path(x_range.clone(), y_range.clone(), &bytes[..=mid]).is_some() {
+            l = mid + 1;
+        } else {
+            r = mid;
+        }
+    }
+    bytes.get(l).copied()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = (Vec<(i32, i32)>, Params);
+    type Answer1 = Result<usize, String>;
+    type Answer2 = Result<(i32, i32), String>;
+
+    const PARAMS: &'static [Param] = &[
+        // The largest coordinate on either axis.
+        Param {
+            name: "size",
+            default: 70,
+            min: 0,
+            max: 1000,
+        },
+        // How many bytes have fallen in part 1.
+        Param {
+            name: "bytes",
+            default: 1024,
+            min: 0,
+            max: 1_000_000,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        fn num(input: &str) -> IResult<&str, i32> {
+            preceded(multispace0, map_res(digit1, str::parse))(input)
+        }
+        let byte = separated_pair(num, cut(tag(",")), cut(num));
+        let bytes = error::parse(input, many1(context("a position like `5,4`", byte)))?;
+        let fallen = params.get("bytes") as usize;
+        if fallen > bytes.len() {
+            let expected = format!("{fallen} bytes, as the parameter \"bytes\" says");
+            return Err(Error::missing(input, expected));
+        }
+        Ok((bytes, params.clone()))
+    }
+
+    /// Generates every byte to fall on a memory space `size` bytes square,
+    /// only the first fifth of which leave a path to the exit.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(2) as i32;
+        let mut bytes = iproduct!(0..size, 0..size).collect_vec();
+        bytes.retain(|&pos| pos != (0, 0) && pos != (size - 1, size - 1));
+        let fallen = (size * size / 5) as usize;
+        loop {
+            rng.shuffle(&mut bytes);
+            if path(0..size, 0..size, &bytes[..fallen]).is_some() {
+                let lines = bytes.iter().map(|(x, y)| format!("{x},{y}\n"));
+                let generated = Generated::new(lines.collect());
+                return Some(
+                    generated
+                        .param("size", size as i64 - 1)
+                        .param("bytes", fallen as i64),
+                );
+            }
+        }
+    }
+
+    fn part1((bytes, params): &Self::Parsed<'_>) -> Result<usize, String> {
+        let size = params.get("size") as i32;
+        let fallen = &bytes[..params.get("bytes") as usize];
+        path(0..=size, 0..=size, fallen).ok_or_else(|| "the exit is already cut off".to_string())
+    }
+
+    fn part2((bytes, params): &Self::Parsed<'_>) -> Result<(i32, i32), String> {
+        let size = params.get("size") as i32;
+        compute_2(0..=size, 0..=size, bytes).ok_or_else(|| "the exit is never cut off".to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let mut params = Params::new(Day18::PARAMS);
+        params.set("size", 1).unwrap();
+        params.set("bytes", 3).unwrap();
+        let error = Day18::parse_with("0,1\n1,0\n", &params).unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (3, 1, None));
+        params.set("bytes", 2).unwrap();
+        let parsed = Day18::parse_with("0,1\n1,0\n", &params).unwrap();
+        assert!(Day18::part1(&parsed).is_err());
+        assert_eq!(Day18::part2(&parsed), Ok((1, 0)));
+    }
+}
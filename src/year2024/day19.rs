@@ -0,0 +1,72 @@
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn count(patterns: &[&str], design: &str) -> usize {
+    // memo[i] counts the ways to build the design up to position i
+    let mut memo = vec![0; design.len() + 1];
+    memo[0] = 1;
+    for i in 0..design.len() {
+        for pat in patterns {
+            if design[i..].starts_with(pat) {
+                memo[i + pat.len()] += memo[i];
+            }
+        }
+    }
+    memo[design.len()]
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+        let patterns = lines
+            .next()
+            .ok_or_else(|| Error::missing(input, "a list of towel patterns"))?;
+        Ok((patterns.split(", ").collect(), lines.collect()))
+    }
+
+    /// Generates a hundred towel patterns and `size` designs, some of which
+    /// are impossible.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+        let mut patterns = Vec::new();
+        while patterns.len() < 100 {
+            let len = rng.range(1..9);
+            let pattern: String = (0..len).map(|_| *rng.choose(&COLORS)).collect();
+            // Leave out a single red stripe, so that not every design is possible.
+            if pattern != "r" && !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        let mut input = patterns.join(", ") + "\n\n";
+        for _ in 0..size.max(1) {
+            let mut design = String::new();
+            let len = rng.range(20..60) as usize;
+            while design.len() < len {
+                design += rng.choose(&patterns).as_str();
+            }
+            if rng.chance(0.3) {
+                let i = rng.below(design.len() as u64) as usize;
+                design.insert(i, *rng.choose(&COLORS));
+            }
+            input += &(design + "\n");
+        }
+        Some(Generated::new(input))
+    }
+
+    fn part1((patterns, designs): &Self::Parsed<'_>) -> usize {
+        designs.iter().filter(|d| count(patterns, d) > 0).count()
+    }
+
+    fn part2((patterns, designs): &Self::Parsed<'_>) -> usize {
+        designs.iter().map(|d| count(patterns, d)).sum()
+    }
+}
@@ -0,0 +1,91 @@
+use itertools::Itertools;
+use nom::{
+    character::complete::{digit1, multispace0, space0},
+    combinator::map_res,
+    multi::many1,
+    sequence::preceded,
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn is_safe(report: &[u32]) -> bool {
+    let order = match report.split_first_chunk() {
+        Some(([l, r], _)) => l.cmp(r),
+        None => return true,
+    };
+    report
+        .iter()
+        .tuple_windows()
+        .all(|(l, r)| l.cmp(r) == order && matches!(l.abs_diff(*r), 1..=3))
+}
+
+fn is_safe_2(mut report: Vec<u32>) -> bool {
+    if report.len() < 3 {
+        return true;
+    }
+    let last = report.len() - 1;
+    for i in (0..last).rev() {
+        if is_safe(&report[..last]) {
+            return true;
+        }
+        report.swap(i, last);
+    }
+    is_safe(&report[..last])
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Vec<u32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        fn num(input: &str) -> IResult<&str, u32> {
+            preceded(space0, map_res(digit1, str::parse))(input)
+        }
+        error::parse(input, many1(preceded(multispace0, many1(num))))
+    }
+
+    /// Generates `size` reports, about half of which are safe, and many of
+    /// which are safe but for a single level.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let lines = (0..size.max(1)).map(|_| {
+            let sign = *rng.choose(&[-1, 1]);
+            let mut level = rng.range(25..75);
+            let mut levels = vec![level];
+            for _ in 0..rng.range(4..8) {
+                level += sign * rng.range(1..4);
+                levels.push(level);
+            }
+            if rng.chance(0.5) {
+                let i = rng.below(levels.len() as u64) as usize;
+                levels[i] = rng.range(1..100);
+            }
+            levels.iter().join(" ") + "\n"
+        });
+        Some(Generated::new(lines.collect()))
+    }
+
+    fn part1(reports: &Self::Parsed<'_>) -> usize {
+        reports
+            .iter()
+            .map(|report| is_safe(report))
+            .filter(|safe| *safe)
+            .count()
+    }
+
+    fn part2(reports: &Self::Parsed<'_>) -> usize {
+        reports
+            .iter()
+            .cloned()
+            .map(is_safe_2)
+            .filter(|safe| *safe)
+            .count()
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::RangeBounds,
+};
+
+use itertools::iproduct;
+
+use crate::{
+    cancel,
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Error,
+};
+
+type Pos = (i32, i32);
+
+fn cheats(
+    max_r: i32,
+    dists: &HashMap<(i32, i32), i32>,
+    (y, x): Pos,
+    d1: i32,
+) -> impl Iterator<Item = i32> + '_ {
+    iproduct!(-max_r..=max_r, -max_r..=max_r)
+        .filter_map(move |(dy, dx)| {
+            let r = dy.abs() + dx.abs();
+            (r <= max_r).then_some((dy, dx, r))
+        })
+        .filter_map(move |(dy, dx, r)| dists.get(&(y + dy, x + dx)).map(|&d2| d1 - d2 - r))
+}
+
+pub fn compute(
+    max_cheat: i32,
+    savings_range: impl RangeBounds<i32>,
+    &(ref map, end): &(HashSet<Pos>, Pos),
+) -> usize {
+    let mut queue: VecDeque<_> = [(0, end)].into();
+    let mut dists = HashMap::new();
+    while let Some((dist, pos @ (y, x))) = queue.pop_front() {
+        if !map.contains(&pos) || dists.contains_key(&pos) {
+            continue;
+        }
+        dists.insert(pos, dist);
+        for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (y, x) = (y + dy, x + dx);
+            queue.push_back((dist + 1, (y, x)));
+        }
+    }
+    dists
+        .iter()
+        .inspect(|_| cancel::check())
+        .flat_map(|(&p1, &d1)| cheats(max_cheat, &dists, p1, d1))
+        .filter(|saving| savings_range.contains(saving))
+        .count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = ((HashSet<Pos>, Pos), Params);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [Param] = &[
+        // The fewest picoseconds that a cheat has to save to be counted.
+        Param {
+            name: "min_saving",
+            default: 100,
+            min: 1,
+            max: i32::MAX as i64,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        let mut map = HashSet::new();
+        let mut end = None;
+        for (y, line) in input.trim().lines().enumerate() {
+            let line = line.trim();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let pos = (y as i32, x as i32);
+                match c {
+                    'E' => end = Some(pos),
+                    '#' => continue,
+                    '.' | 'S' => (),
+                    _ => return Err(Error::at(input, &line[i..], "`.`, `#`, `S` or `E`")),
+                }
+                map.insert(pos);
+            }
+        }
+        let end = end.ok_or_else(|| Error::missing(input, "an end tile `E`"))?;
+        Ok(((map, end), params.clone()))
+    }
+
+    /// Generates a racetrack that winds around a grid `size` tiles square.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        // Carve a maze through the cells with odd coordinates, and then keep
+        // only the path from the start to the cell furthest from it.
+        let size = (size.max(5) - 1) / 2 * 2 + 1;
+        let start = (size - 2, 1);
+        let mut parents = HashMap::from([(start, start)]);
+        let mut stack = vec![start];
+        let mut end = (0, start);
+        while let Some(&(y, x)) = stack.last() {
+            let mut next = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+                .map(|(dy, dx)| ((y as i64 + dy) as usize, (x as i64 + dx) as usize))
+                .into_iter()
+                .filter(|&pos| pos.0 < size && pos.1 < size && !parents.contains_key(&pos))
+                .collect::<Vec<_>>();
+            if next.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut next);
+            parents.insert(next[0], (y, x));
+            stack.push(next[0]);
+            end = end.max((stack.len(), next[0]));
+        }
+        let mut grid = vec![vec!['#'; size]; size];
+        let mut pos = end.1;
+        while pos != start {
+            let parent = parents[&pos];
+            grid[pos.0][pos.1] = '.';
+            grid[(pos.0 + parent.0) / 2][(pos.1 + parent.1) / 2] = '.';
+            pos = parent;
+        }
+        grid[start.0][start.1] = 'S';
+        grid[end.1 .0][end.1 .1] = 'E';
+        let rows = grid.into_iter().map(|row| row.into_iter().chain(['\n']));
+        Some(Generated::new(rows.flatten().collect()))
+    }
+
+    fn part1((track, params): &Self::Parsed<'_>) -> usize {
+        compute(2, params.get("min_saving") as i32.., track)
+    }
+
+    fn part2((track, params): &Self::Parsed<'_>) -> usize {
+        compute(20, params.get("min_saving") as i32.., track)
+    }
+}
@@ -6,19 +6,20 @@ use std::{
 
 use itertools::{chain, Itertools};
 
-fn parse(input: &str) -> impl Iterator<Item = &str> {
-    input
-        .lines()
-        .map(str::trim)
-        .filter_map(|l| l.strip_suffix("A"))
-}
+use crate::{
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Error,
+};
+
+type Memo = HashMap<((i32, i32), (i32, i32), usize), u64>;
 
 fn make_keypad<const M: usize, const N: usize>(
     chars: &[[char; N]; M],
 ) -> HashMap<(i32, i32), char> {
     let mut result = HashMap::new();
-    for (y, row) in chars.into_iter().enumerate() {
-        for (x, &c) in row.into_iter().enumerate() {
+    for (y, row) in chars.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
             if c != ' ' {
                 result.insert((y as i32, x as i32), c);
             }
@@ -28,12 +29,12 @@ fn make_keypad<const M: usize, const N: usize>(
 }
 
 fn calc_move_cost(
-    memo: &mut HashMap<((i32, i32), (i32, i32), usize), u64>,
+    memo: &mut Memo,
     pads: &[&HashMap<(i32, i32), char>],
     start: (i32, i32),
     end: (i32, i32),
 ) -> u64 {
-    let Some((&keypad, &dpad)) = pads.into_iter().next_tuple() else {
+    let Some((&keypad, &dpad)) = pads.iter().next_tuple() else {
         return 1;
     };
     if end == start {
@@ -72,11 +73,7 @@ fn calc_move_cost(
     panic!()
 }
 
-fn compute(
-    memo: &mut HashMap<((i32, i32), (i32, i32), usize), u64>,
-    n_robots: usize,
-    code: &str,
-) -> u64 {
+fn compute(memo: &mut Memo, n_robots: usize, code: &str) -> u64 {
     #[rustfmt::skip]
     let dpad = make_keypad(&[
         [' ', '^', 'A'],
@@ -99,34 +96,63 @@ fn compute(
         .sum()
 }
 
-pub fn solve(input: &str) -> u64 {
-    let mut memo = HashMap::new();
-    parse(input)
-        .map(|code| compute(&mut memo, 3, code) * code.parse::<u64>().unwrap())
-        .sum()
-}
+pub struct Day21;
 
-pub fn solve_2(input: &str) -> u64 {
-    let mut memo = HashMap::new();
-    parse(input)
-        .map(|code| compute(&mut memo, 26, code) * code.parse::<u64>().unwrap())
-        .sum()
-}
+impl Solution for Day21 {
+    type Parsed<'a> = (Vec<&'a str>, Params);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    // The number of directional keypads in the chain, including your own.
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "robots_1",
+            default: 3,
+            min: 0,
+            max: 35,
+        },
+        Param {
+            name: "robots_2",
+            default: 26,
+            min: 0,
+            max: 35,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        let codes = input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| match line.strip_suffix("A") {
+                Some(code) if code.parse::<u64>().is_ok() => Ok(code),
+                _ => Err(Error::at(input, line, "a code like `029A`")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((codes, params.clone()))
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Generates `size` door codes.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let codes = (0..size.max(1)).map(|_| format!("{:03}A\n", rng.below(1000)));
+        Some(Generated::new(codes.collect()))
+    }
 
-    const SAMPLE: &'static str = "
-        029A
-        980A
-        179A
-        456A
-        379A
-    ";
+    fn part1((codes, params): &Self::Parsed<'_>) -> u64 {
+        let n_robots = params.get("robots_1") as usize;
+        let mut memo = HashMap::new();
+        codes
+            .iter()
+            .map(|code| compute(&mut memo, n_robots, code) * code.parse::<u64>().unwrap())
+            .sum()
+    }
 
-    #[test]
-    fn test_sample() {
-        assert_eq!(solve(SAMPLE), 126384);
+    fn part2((codes, params): &Self::Parsed<'_>) -> u64 {
+        let n_robots = params.get("robots_2") as usize;
+        let mut memo = HashMap::new();
+        codes
+            .iter()
+            .map(|code| compute(&mut memo, n_robots, code) * code.parse::<u64>().unwrap())
+            .sum()
     }
 }
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, hash::Hash, iter::once};
+
+use itertools::Itertools;
+
+use crate::{
+    cancel,
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn simulate(mut num: u64, count: usize) -> impl Iterator<Item = u64> {
+    let mix = |a: u64, b: u64| (a ^ b) % 16777216;
+    once(num).chain((0..count).map(move |_| {
+        num = mix(num, num * 64);
+        num = mix(num, num / 32);
+        num = mix(num, num * 2048);
+        num
+    }))
+}
+
+trait Combine<K, V> {
+    fn combine(self, f: impl Fn(V, V) -> V) -> HashMap<K, V>;
+}
+
+impl<T, K, V> Combine<K, V> for T
+where
+    T: IntoIterator<Item = (K, V)>,
+    K: Hash + Eq,
+    V: Copy,
+{
+    fn combine(self, f: impl Fn(V, V) -> V) -> HashMap<K, V> {
+        let mut map = HashMap::new();
+        for (k, v) in self {
+            map.entry(k)
+                .and_modify(|old_v: &mut V| *old_v = f(*old_v, v))
+                .or_insert(v);
+        }
+        map
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.parse().map_err(|_| Error::at(input, line, "a number")))
+            .collect()
+    }
+
+    /// Generates `size` buyers' initial secret numbers.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let secrets = (0..size.max(1)).map(|_| format!("{}\n", rng.range(1..16777216)));
+        Some(Generated::new(secrets.collect()))
+    }
+
+    fn part1(nums: &Self::Parsed<'_>) -> u64 {
+        nums.iter()
+            .inspect(|_| cancel::check())
+            .filter_map(|&num| simulate(num, 2000).last())
+            .sum()
+    }
+
+    fn part2(nums: &Self::Parsed<'_>) -> u64 {
+        nums.iter()
+            .inspect(|_| cancel::check())
+            .flat_map(|&num| {
+                simulate(num, 2000)
+                    .map(|n| n % 10)
+                    .tuple_windows()
+                    .map(|(a, b)| (b as i8 - a as i8, b))
+                    .tuple_windows()
+                    .map(|((a, _), (b, _), (c, _), (d, n))| ((a, b, c, d), n))
+                    .combine(|n, _| n)
+            })
+            .combine(u64::saturating_add)
+            .into_values()
+            .max()
+            .unwrap()
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{BTreeSet, HashSet};
+
+use itertools::{iproduct, Itertools};
+
+use crate::{
+    cancel,
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = (HashSet<&'a str>, HashSet<(&'a str, &'a str)>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut edges = HashSet::new();
+        for line in input.trim().lines() {
+            let line = line.trim();
+            let Some((a, b)) = line.split("-").next_tuple() else {
+                return Err(Error::at(input, line, "two computers joined by `-`"));
+            };
+            edges.insert((a, b));
+            edges.insert((b, a));
+        }
+        let names = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+        Ok((names, edges))
+    }
+
+    /// Generates a network of `size` computers, at most 676, each joined to
+    /// a dozen or so others, with a LAN party hidden among them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut names = iproduct!('a'..='z', 'a'..='z')
+            .map(|(a, b)| format!("{a}{b}"))
+            .collect_vec();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(2, names.len()));
+        let chance = 12.0 / names.len() as f64;
+        let party = &names[..names.len().min(13)];
+        let edges: BTreeSet<(_, _)> = names
+            .iter()
+            .tuple_combinations()
+            .filter(|_| rng.chance(chance))
+            .chain(party.iter().tuple_combinations())
+            .collect();
+        let mut edges = edges.into_iter().collect_vec();
+        rng.shuffle(&mut edges);
+        let lines = edges.iter().map(|(a, b)| format!("{a}-{b}\n"));
+        Some(Generated::new(lines.collect()))
+    }
+
+    fn part1((names, edges): &Self::Parsed<'_>) -> usize {
+        names
+            .iter()
+            .tuple_combinations()
+            .inspect(|_| cancel::check())
+            .filter(|(a, b, c)| a.starts_with("t") || b.starts_with("t") || c.starts_with("t"))
+            .filter(|&(&a, &b, &c)| {
+                edges.contains(&(a, b)) && edges.contains(&(a, c)) && edges.contains(&(b, c))
+            })
+            .count()
+    }
+
+    fn part2((names, edges): &Self::Parsed<'_>) -> String {
+        let names = names.iter().collect_vec();
+        let mut stack = vec![(Vec::<&str>::new(), 0)];
+        let mut best = Vec::new();
+        while let Some((v, i)) = stack.pop() {
+            cancel::check();
+            if i >= names.len() {
+                if v.len() > best.len() {
+                    best = v;
+                }
+                continue;
+            }
+            let a = names[i];
+            if v.iter().all(|&b| edges.contains(&(a, b))) {
+                let mut v = v.clone();
+                v.push(a);
+                stack.push((v, i + 1));
+            }
+            stack.push((v, i + 1));
+        }
+        best.into_iter().sorted().join(",")
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    iter::from_fn,
+};
+
+use itertools::{chain, iproduct, Itertools};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, alphanumeric0, digit1, multispace0, space0},
+    combinator::{cut, map, map_opt, verify},
+    error::context,
+    multi::many1,
+    sequence::{pair, preceded, separated_pair, tuple},
+};
+
+use crate::{
+    cancel,
+    error::{self, IResult},
+    log::{debug, info, trace},
+    rng::Rng,
+    solution::{Generated, Param, Params, Solution},
+    Error,
+};
+
+fn and(a: bool, b: bool) -> bool {
+    a & b
+}
+
+fn or(a: bool, b: bool) -> bool {
+    a | b
+}
+
+fn xor(a: bool, b: bool) -> bool {
+    a ^ b
+}
+
+pub type Op = fn(bool, bool) -> bool;
+
+/// Maps each gate's output wire to its two input wires and its operation.
+pub type Edges = HashMap<String, (String, String, Op)>;
+
+/// Reads the number whose bits are the wires named `{prefix}00`, `{prefix}01`...
+pub fn read_num(vals: &HashMap<String, bool>, prefix: char) -> u64 {
+    vals.iter()
+        .filter_map(|(a, v)| a.strip_prefix(prefix).map(|a| (a, v)))
+        .filter_map(|(n, v)| n.parse::<u8>().ok().map(|n| (n, v)))
+        .map(|(n, &v)| (v as u64) << n)
+        .sum()
+}
+
+/// Simulates the circuit from the initial wire values, returning the value of
+/// every wire, or `None` if the gates contain a loop.
+pub fn compute(
+    init: impl IntoIterator<Item = (String, bool)>,
+    edges: &Edges,
+) -> Option<HashMap<String, bool>> {
+    let mut todo: Vec<(String, bool)> = init.into_iter().collect_vec();
+    let mut deps: HashMap<String, Vec<String>> = HashMap::new();
+    let mut reqs: HashMap<String, usize> = HashMap::new();
+    let mut vals: HashMap<String, bool> = HashMap::new();
+    for (c, (a, b, _)) in edges {
+        deps.entry(a.clone()).or_default().push(c.clone());
+        deps.entry(b.clone()).or_default().push(c.clone());
+        reqs.insert(c.clone(), if a == b { 1 } else { 2 });
+    }
+    while let Some((a, v)) = todo.pop() {
+        let a_deps = deps.get(&a);
+        vals.insert(a, v);
+        for c in a_deps.into_iter().flatten() {
+            let Some(count) = reqs.get_mut(c) else {
+                continue;
+            };
+            if *count == 0 {
+                return None; // There is a loop
+            }
+            *count -= 1;
+            if *count == 0 {
+                let (a, b, op) = edges.get(c).unwrap();
+                let &a = vals.get(a).unwrap();
+                let &b = vals.get(b).unwrap();
+                todo.push((c.clone(), op(a, b)));
+            }
+        }
+    }
+    if reqs.into_values().any(|v| v != 0) {
+        None
+    } else {
+        Some(vals)
+    }
+}
+
+fn swap_map_keys<K: Hash + Eq + Clone, V>(map: &mut HashMap<K, V>, a: &K, b: &K) {
+    let a_val = map.remove(a).unwrap();
+    let b_val = map.remove(b).unwrap();
+    map.insert(a.clone(), b_val);
+    map.insert(b.clone(), a_val);
+}
+
+fn make_init(prefix: char, bits: u32, num: u64) -> impl Iterator<Item = (String, bool)> {
+    (0..bits).map(move |i| (format!("{prefix}{i:02}"), (num >> i) & 1 != 0))
+}
+
+fn output_deps<'a>(edges: &'a Edges, start: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut stack = vec![start];
+    from_fn(move || {
+        while let Some(o) = stack.pop() {
+            if let Some((a, b, _)) = edges.get(o) {
+                stack.push(a);
+                stack.push(b);
+                return Some(o);
+            };
+        }
+        None
+    })
+}
+
+fn check_adder_n(edges: &Edges, bits: u32, n: u32) -> bool {
+    let (shift, len) = match n {
+        0 => (0, 2),
+        _ if n == bits => (n - 1, 2),
+        _ => (n - 1, 4),
+    };
+    for (x, y) in iproduct!(0..len, 0..len) {
+        let (x, y) = (x << shift, y << shift);
+        let Some(vals) = compute(
+            chain!(make_init('x', bits, x), make_init('y', bits, y)),
+            edges,
+        ) else {
+            return false;
+        };
+        if vals.get(&format!("z{n:02}")) != Some(&((x + y) & (1 << n) != 0)) {
+            return false;
+        }
+    }
+    true
+}
+
+fn find_solution(
+    edges: &mut Edges,
+    bits: u32,
+    good_bits: u32,
+    mut swappable_outputs: HashSet<String>,
+    swaps: usize,
+) -> Option<Vec<String>> {
+    let Some(bad_bit) = (good_bits..=bits).find(|&bit| !check_adder_n(edges, bits, bit)) else {
+        return Some(vec![]);
+    };
+    if swaps == 4 {
+        return None;
+    }
+    debug!("Bit {bad_bit} is wrong after {swaps} swaps");
+    for bit in good_bits..bad_bit {
+        for a in output_deps(edges, &format!("z{bit:02}")) {
+            swappable_outputs.remove(a);
+        }
+    }
+    for (a, b) in swappable_outputs.iter().tuple_combinations() {
+        cancel::check();
+        swap_map_keys(edges, a, b);
+        if check_adder_n(edges, bits, bad_bit) {
+            trace!("Swapping {a} and {b} fixes bit {bad_bit}");
+            if let Some(mut solution) =
+                find_solution(edges, bits, bad_bit, swappable_outputs.clone(), swaps + 1)
+            {
+                solution.extend([a.clone(), b.clone()]);
+                return Some(solution);
+            }
+        }
+        swap_map_keys(edges, a, b);
+    }
+    None
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = (Vec<(String, bool)>, Edges, Params);
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    const PARAMS: &'static [Param] = &[
+        // The width of the adder's inputs.
+        Param {
+            name: "bits",
+            default: 45,
+            min: 2,
+            max: 63,
+        },
+    ];
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Parsed<'a>, Error> {
+        fn reg(input: &str) -> IResult<&str, String> {
+            map(
+                preceded(space0, verify(alphanumeric0, |s: &str| s.len() == 3)),
+                str::to_string,
+            )(input)
+        }
+        fn bool_num(input: &str) -> IResult<&str, bool> {
+            preceded(
+                space0,
+                map_opt(digit1, |n| match n {
+                    "1" => Some(true),
+                    "0" => Some(false),
+                    _ => None,
+                }),
+            )(input)
+        }
+        let op = preceded(
+            space0,
+            map_opt(alpha1, |name| match name {
+                "AND" => Some(and as Op),
+                "OR" => Some(or as Op),
+                "XOR" => Some(xor as Op),
+                _ => None,
+            }),
+        );
+        let value = context(
+            "a wire like `x00: 1`",
+            separated_pair(reg, tag(":"), cut(bool_num)),
+        );
+        let edge = context(
+            "a gate like `x00 AND y00 -> z00`",
+            tuple((reg, cut(op), cut(reg), cut(preceded(tag(" ->"), reg)))),
+        );
+        let (init, edges) = error::parse(
+            input,
+            pair(
+                many1(preceded(multispace0, value)),
+                many1(preceded(multispace0, edge)),
+            ),
+        )?;
+        let edges = edges
+            .into_iter()
+            .map(|(a, op, b, c)| (c.clone(), (a.clone(), b.clone(), op)))
+            .collect();
+        Ok((init, edges, params.clone()))
+    }
+
+    /// Generates a ripple-carry adder of `size` bits, from 10 to 63, with
+    /// the outputs of four pairs of gates swapped, each pair within one bit.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let bits = size.clamp(10, 63);
+        let mut names = HashSet::new();
+        let mut wire = || loop {
+            let name: String = (0..3)
+                .map(|_| (b'a' + rng.below(23) as u8) as char)
+                .collect();
+            if names.insert(name.clone()) {
+                return name;
+            }
+        };
+        // Each gate is `(a, op, b, output)`, and each bit's gates are listed
+        // as `[input XOR, output XOR, input AND, carry AND, carry OR]`.
+        let mut gates = vec![
+            (
+                "x00".to_string(),
+                "XOR",
+                "y00".to_string(),
+                "z00".to_string(),
+            ),
+            ("x00".to_string(), "AND", "y00".to_string(), wire()),
+        ];
+        for i in 1..bits {
+            let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+            let carry = gates.last().unwrap().3.clone();
+            let (sum, and, carry_and) = (wire(), wire(), wire());
+            let carry_out = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                wire()
+            };
+            gates.extend([
+                (x.clone(), "XOR", y.clone(), sum.clone()),
+                (sum.clone(), "XOR", carry.clone(), z),
+                (x, "AND", y, and.clone()),
+                (sum, "AND", carry, carry_and.clone()),
+                (and, "OR", carry_and, carry_out),
+            ]);
+        }
+        // Swap within one bit of each quarter of the adder, with correct bits
+        // between them as in the puzzle, so that each swap can be found alone.
+        let quarter = (bits - 2) / 4;
+        for i in 0..4 {
+            let bit = 1 + i * quarter + rng.below(quarter as u64 - 1) as usize;
+            // The kinds of swap in the puzzle: the output with any of the
+            // bit's other gates but the input XOR, which would make a loop, or
+            // the input XOR with the input AND.
+            let &(a, b) = rng.choose(&[(1, 2), (1, 3), (1, 4), (0, 2)]);
+            let first = 2 + (bit - 1) * 5;
+            let (a, b) = (first + a, first + b);
+            let output = gates[a].3.clone();
+            gates[a].3 = std::mem::replace(&mut gates[b].3, output);
+        }
+        let mut input = String::new();
+        for prefix in ['x', 'y'] {
+            for i in 0..bits {
+                input += &format!("{prefix}{i:02}: {}\n", rng.below(2));
+            }
+        }
+        input.push('\n');
+        rng.shuffle(&mut gates);
+        for (a, op, b, output) in gates {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            input += &format!("{a} {op} {b} -> {output}\n");
+        }
+        Some(Generated::new(input).param("bits", bits as i64))
+    }
+
+    fn part1((init, edges, _): &Self::Parsed<'_>) -> u64 {
+        let vals = compute(init.iter().cloned(), edges).unwrap();
+        read_num(&vals, 'z')
+    }
+
+    fn part2((_, edges, params): &Self::Parsed<'_>) -> String {
+        let bits = params.get("bits") as u32;
+        let mut edges = edges.clone();
+        let carry = check_adder_n(&edges, bits, bits);
+        debug!("Before any swaps, the final carry is right: {carry}");
+        let swappable_outputs: HashSet<_> = edges.keys().cloned().collect();
+        let swaps = find_solution(&mut edges, bits, 0, swappable_outputs, 0).unwrap();
+        info!("Found {} swaps that fix the adder", swaps.len() / 2);
+        swaps.into_iter().sorted().join(",")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_gate() {
+        let error = Day24::parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (4, 5, Some('N')));
+        assert_eq!(error.expected, "a gate like `x00 AND y00 -> z00`");
+    }
+
+    #[test]
+    fn test_bad_wire() {
+        let error = Day24::parse("x00: 1\ny00: 2\n\nx00 AND y00 -> z00\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (2, 6, Some('2')));
+        assert_eq!(error.expected, "a wire like `x00: 1`");
+    }
+}
@@ -0,0 +1,70 @@
+use std::{convert::Infallible, iter::zip};
+
+use itertools::{iproduct, Itertools};
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = (Vec<[u8; 5]>, Vec<[u8; 5]>);
+    type Answer1 = usize;
+    type Answer2 = Infallible;
+
+    const PARTS: u32 = 1;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        if let Some(i) = input.find(|c: char| !matches!(c, '.' | '#') && !c.is_whitespace()) {
+            return Err(Error::at(input, &input[i..], "`.` or `#`"));
+        }
+        let (mut keys, mut locks) = (Vec::new(), Vec::new());
+        let mut chars = input.chars().filter(|&c| c == '.' || c == '#');
+        loop {
+            let mut heights = [0; 5];
+            let mut is_lock = false;
+            for y in 0..7 {
+                for h in &mut heights {
+                    match chars.next() {
+                        Some('#') => *h += 1,
+                        Some(_) => continue,
+                        None => return Ok((keys, locks)),
+                    }
+                }
+                if y == 0 {
+                    is_lock = heights.iter().all(|&h| h == 1);
+                }
+            }
+            if is_lock { &mut locks } else { &mut keys }.push(heights);
+        }
+    }
+
+    /// Generates `size` schematics of locks and keys.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut schematics = (0..size.max(1)).map(|_| {
+            let heights: [u64; 5] = [(); 5].map(|_| rng.below(6));
+            let is_lock = rng.chance(0.5);
+            let rows = (0..7).map(|y| {
+                // How far the row is from the solid edge.
+                let depth = if is_lock { y } else { 6 - y };
+                let row = heights.map(|h| if depth <= h { '#' } else { '.' });
+                row.into_iter().chain(['\n']).collect::<String>()
+            });
+            rows.collect::<String>()
+        });
+        Some(Generated::new(schematics.join("\n")))
+    }
+
+    fn part1((keys, locks): &Self::Parsed<'_>) -> usize {
+        iproduct!(keys, locks)
+            .filter(|(k, l)| zip(*k, *l).all(|(h1, h2)| h1 + h2 <= 7))
+            .count()
+    }
+
+    fn part2(_: &Self::Parsed<'_>) -> Infallible {
+        unreachable!("Day 25 has no second part")
+    }
+}
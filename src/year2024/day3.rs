@@ -0,0 +1,128 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::digit1,
+    combinator::{map, map_res, rest, verify},
+    multi::{many1, many_till},
+    sequence::{delimited, separated_pair, terminated},
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+pub enum Instr {
+    Do,
+    Dont,
+    Mul(u32, u32),
+}
+
+fn run<const P2: bool>(instrs: &[Instr]) -> u32 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for i in instrs {
+        match i {
+            Instr::Do => enabled = true,
+            Instr::Dont => enabled = false,
+            &Instr::Mul(a, b) => {
+                if enabled || !P2 {
+                    sum += a * b
+                }
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Vec<Instr>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        fn num(input: &str) -> IResult<&str, u32> {
+            map_res(verify(digit1, |s: &str| s.len() <= 3), str::parse)(input)
+        }
+        let mul = delimited(tag("mul("), separated_pair(num, tag(","), num), tag(")"));
+        let instr = alt((
+            map(tag("do()"), |_| Instr::Do),
+            map(tag("don't()"), |_| Instr::Dont),
+            map(mul, |(a, b)| Instr::Mul(a, b)),
+        ));
+        let find = |p| map(many_till(take(1usize), p), |(_, g)| g);
+        error::parse(input, terminated(many1(find(instr)), rest))
+    }
+
+    /// Generates `size` instructions, separated by junk that includes broken
+    /// instructions.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        const JUNK: &[&str] = &[
+            "%",
+            "&",
+            "*",
+            "+",
+            "-",
+            "/",
+            ":",
+            ";",
+            "<",
+            ">",
+            "?",
+            "@",
+            "[",
+            "]",
+            "^",
+            "_",
+            "{",
+            "}",
+            "(",
+            ")",
+            ",",
+            " ",
+            "'",
+            "!",
+            "#",
+            "$",
+            "\n",
+            "why",
+            "from",
+            "what",
+            "select",
+            "mul",
+            "mul(",
+            "mul[",
+            "do",
+            "don't",
+            "1",
+            "42",
+            "mul ( 2,4 )",
+        ];
+        // Keep the products small enough that the answers can't overflow.
+        let max = (u32::MAX as f64 / size.max(1) as f64).sqrt().min(1000.0) as i64;
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..rng.below(8) {
+                input += *rng.choose(JUNK);
+            }
+            input += &match rng.below(10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                _ => format!("mul({},{})", rng.range(1..max), rng.range(1..max)),
+            };
+        }
+        Some(Generated::new(input + "\n"))
+    }
+
+    fn part1(instrs: &Self::Parsed<'_>) -> u32 {
+        run::<false>(instrs)
+    }
+
+    fn part2(instrs: &Self::Parsed<'_>) -> u32 {
+        run::<true>(instrs)
+    }
+}
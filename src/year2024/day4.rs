@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use itertools::iproduct;
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn contains(
+    grid: &HashMap<(isize, isize), char>,
+    needle: &str,
+    (mut y, mut x, dy, dx): (isize, isize, isize, isize),
+) -> bool {
+    for c in needle.chars() {
+        if grid.get(&(y, x)) != Some(&c) {
+            return false;
+        }
+        y += dy;
+        x += dx;
+    }
+    true
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = (HashMap<(isize, isize), char>, isize, isize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut result = HashMap::new();
+        for (y, line) in input.trim().lines().enumerate() {
+            for (x, c) in line.trim().chars().enumerate() {
+                result.insert((y as isize, x as isize), c);
+            }
+        }
+        let missing = || Error::missing(input, "a grid of letters");
+        let width = result
+            .keys()
+            .map(|(_, x)| x + 1)
+            .max()
+            .ok_or_else(missing)?;
+        let height = result
+            .keys()
+            .map(|(y, _)| y + 1)
+            .max()
+            .ok_or_else(missing)?;
+        Ok((result, height, width))
+    }
+
+    /// Generates a word search `size` letters square.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.extend((0..size.max(1)).map(|_| *rng.choose(&['X', 'M', 'A', 'S'])));
+            input.push('\n');
+        }
+        Some(Generated::new(input))
+    }
+
+    fn part1(&(ref grid, height, width): &Self::Parsed<'_>) -> usize {
+        iproduct!(0..height, 0..width, [-1, 0, 1], [-1, 0, 1])
+            .filter(|&p| contains(grid, "XMAS", p))
+            .count()
+    }
+
+    fn part2(&(ref grid, height, width): &Self::Parsed<'_>) -> usize {
+        iproduct!(0..height, 0..width, ["MAS", "SAM"], ["MAS", "SAM"])
+            .filter(|(y, x, n1, n2)| {
+                contains(grid, n1, (y - 1, x - 1, 1, 1))
+                    && contains(grid, n2, (y - 1, x + 1, 1, -1))
+            })
+            .count()
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0, multispace1},
+    combinator::{cut, map, map_res},
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, separated_pair},
+};
+
+use crate::{
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn difference<'a, T, Q>(
+    a: impl IntoIterator<Item = T>,
+    b: impl IntoIterator<Item = Q>,
+) -> impl Iterator<Item = T>
+where
+    T: 'a + Hash + Eq,
+    Q: Eq + Hash + Borrow<T>,
+{
+    let b: HashSet<Q> = b.into_iter().collect();
+    a.into_iter().filter(move |n| !b.contains(n))
+}
+
+/// Orders `nodes` so that for every rule `(a, b)`, `a` comes before `b`.
+/// Returns `None` if `nodes` is already in order.
+pub fn topo_sort(rules: &HashSet<(u32, u32)>, nodes: &[u32]) -> Option<Vec<u32>> {
+    // edges maps from pre-nodes to after-nodes.
+    let mut edges = HashMap::<u32, Vec<u32>>::new();
+    // num_deps maps nodes to the number of nodes that have to precede it.
+    let mut num_deps = HashMap::<u32, usize>::new();
+    let mut all_ok = true;
+    for (&a, &b) in nodes.iter().tuple_combinations() {
+        if rules.contains(&(a, b)) {
+            edges.entry(a).or_default().push(b);
+            *num_deps.entry(b).or_default() += 1;
+        }
+        if rules.contains(&(b, a)) {
+            edges.entry(b).or_default().push(a);
+            *num_deps.entry(a).or_default() += 1;
+            all_ok = false;
+        }
+    }
+    if all_ok {
+        return None;
+    }
+    // next_nodes is the set of nodes that don't depend on any others.
+    let mut next_nodes: Vec<u32> = difference(nodes, num_deps.keys()).copied().collect();
+    let mut result = Vec::new();
+    while let Some(n) = next_nodes.pop() {
+        result.push(n);
+        for &d in edges.get(&n).into_iter().flatten() {
+            let num = num_deps.get_mut(&d).unwrap();
+            *num -= 1;
+            if *num == 0 {
+                next_nodes.push(d);
+            }
+        }
+    }
+    Some(result)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = (HashSet<(u32, u32)>, Vec<Vec<u32>>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        fn num(input: &str) -> IResult<&str, u32> {
+            map_res(digit1, str::parse)(input)
+        }
+        let rule = separated_pair(num, tag("|"), cut(num));
+        let rules = map(many1(preceded(multispace0, rule)), |rules| {
+            rules.into_iter().collect()
+        });
+        let update = separated_list1(tag(","), num);
+        let updates = many1(preceded(multispace1, update));
+        error::parse(input, pair(rules, updates))
+    }
+
+    /// Generates a rule for every pair of 49 pages, and `size` updates, about
+    /// half of which are in order.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut pages = (10..100).collect_vec();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+        let mut rules = pages.iter().tuple_combinations().collect_vec();
+        rng.shuffle(&mut rules);
+        let mut input: String = rules.iter().map(|(a, b)| format!("{a}|{b}\n")).collect();
+        for _ in 0..size.max(1) {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(rng.below(10) as usize * 2 + 5);
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|p| p == page));
+            }
+            input += &format!("\n{}", update.iter().join(","));
+        }
+        Some(Generated::new(input + "\n"))
+    }
+
+    fn part1((rules, updates): &Self::Parsed<'_>) -> u32 {
+        updates
+            .iter()
+            .filter(|update| topo_sort(rules, update).is_none())
+            .map(|update| update[update.len() / 2])
+            .sum()
+    }
+
+    fn part2((rules, updates): &Self::Parsed<'_>) -> u32 {
+        updates
+            .iter()
+            .filter_map(|update| topo_sort(rules, update))
+            .map(|update| update[update.len() / 2])
+            .sum()
+    }
+}
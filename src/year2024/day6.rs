@@ -0,0 +1,158 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter::from_fn,
+};
+
+use itertools::{iproduct, Itertools};
+
+use crate::{
+    animate::{self, step},
+    cancel,
+    render::{self, frame, Image, Palette},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn walk(
+    grid: &HashMap<(i32, i32), char>,
+    mut pos: (i32, i32),
+    mut dir: (i32, i32),
+) -> impl Iterator<Item = ((i32, i32), (i32, i32))> + '_ {
+    from_fn(move || {
+        let next_pos = (pos.0 + dir.0, pos.1 + dir.1);
+        if *grid.get(&next_pos)? == '#' {
+            dir = (dir.1, -dir.0);
+        } else {
+            pos = next_pos;
+        }
+        Some((pos, dir))
+    })
+    .fuse()
+}
+
+const PALETTE: Palette = &[
+    ('.', [24, 24, 40]),
+    ('#', [128, 128, 144]),
+    ('X', [224, 192, 64]),
+    ('^', [224, 64, 64]),
+];
+
+/// Records the guard's walk, a frame every hundredth of the way, and shows
+/// every step of it.
+fn render_walk(grid: &HashMap<(i32, i32), char>, start: (i32, i32)) {
+    let steps = walk(grid, start, (-1, 0)).collect_vec();
+    let height = grid.keys().map(|&(y, _)| y as usize + 1).max().unwrap_or(0);
+    let width = grid.keys().map(|&(_, x)| x as usize + 1).max().unwrap_or(0);
+    let mut visited = HashSet::from([start]);
+    for (i, &(pos, _)) in steps.iter().enumerate() {
+        visited.insert(pos);
+        let image = || {
+            Image::from_fn(width, height, PALETTE, |x, y| match (y as i32, x as i32) {
+                tile if tile == pos => '^',
+                tile if visited.contains(&tile) => 'X',
+                tile => grid.get(&tile).copied().unwrap_or(' '),
+            })
+        };
+        step!("walk", image());
+        if i % (steps.len() / 100).max(1) == 0 || i == steps.len() - 1 {
+            frame!("walk", image());
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = (HashMap<(i32, i32), char>, (i32, i32));
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut start = None;
+        let mut result = HashMap::new();
+        for (y, line) in input.trim().lines().enumerate() {
+            let line = line.trim();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let pos = (y as i32, x as i32);
+                match c {
+                    '^' => start = Some(pos),
+                    '.' | '#' => (),
+                    _ => return Err(Error::at(input, &line[i..], "`.`, `#` or `^`")),
+                }
+                result.insert(pos, c);
+            }
+        }
+        let start = start.ok_or_else(|| Error::missing(input, "a guard `^`"))?;
+        Ok((result, start))
+    }
+
+    /// Generates a lab `size` tiles square, strewn with obstructions, that
+    /// the guard walks out of.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(1) as i32;
+        loop {
+            let mut grid = HashMap::new();
+            for (y, x) in iproduct!(0..size, 0..size) {
+                grid.insert((y, x), if rng.chance(0.1) { '#' } else { '.' });
+            }
+            let start = (
+                rng.range(0..size as i64) as i32,
+                rng.range(0..size as i64) as i32,
+            );
+            grid.insert(start, '^');
+            // Try again if the guard gets stuck in a loop.
+            let mut seen = HashSet::new();
+            if walk(&grid, start, (-1, 0)).all(|step| seen.insert(step)) {
+                let rows = (0..size).map(|y| (0..size).map(|x| grid[&(y, x)]).join(""));
+                return Some(Generated::new(rows.map(|row| row + "\n").collect()));
+            }
+        }
+    }
+
+    fn part1(&(ref grid, pos): &Self::Parsed<'_>) -> usize {
+        if render::enabled() || animate::enabled() {
+            render_walk(grid, pos);
+        }
+        walk(grid, pos, (-1, 0)).unique_by(|&(pos, _)| pos).count()
+    }
+
+    fn part2(&(ref grid, start): &Self::Parsed<'_>) -> usize {
+        let dir = (-1, 0);
+        let mut visited_dirs: HashSet<((i32, i32), (i32, i32))> = HashSet::new();
+        let mut visited: HashSet<(i32, i32)> = HashSet::new();
+        let mut result = 0;
+        for (pos, dir) in walk(grid, start, dir) {
+            cancel::check();
+            visited_dirs.insert((pos, dir));
+            visited.insert(pos);
+            let block_pos = (pos.0 + dir.0, pos.1 + dir.1);
+            if visited.contains(&block_pos)
+                || matches!(grid.get(&block_pos), None | Some('#' | '^'))
+            {
+                continue;
+            }
+            let mut grid = grid.clone();
+            grid.insert(block_pos, '#');
+            let mut visited_dirs = visited_dirs.clone();
+            if walk(&grid, pos, (dir.1, -dir.0)).any(|k| !visited_dirs.insert(k)) {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    /// Tries an obstruction on every open tile, walking the whole way from
+    /// the start each time.
+    fn reference2(&(ref grid, start): &Self::Parsed<'_>) -> Option<usize> {
+        let open = grid.iter().filter(|&(_, &c)| c == '.');
+        let loops = open.filter(|&(&pos, _)| {
+            let mut grid = grid.clone();
+            grid.insert(pos, '#');
+            let mut seen = HashSet::new();
+            let escapes = walk(&grid, start, (-1, 0)).all(|step| seen.insert(step));
+            !escapes
+        });
+        Some(loops.count())
+    }
+}
@@ -0,0 +1,120 @@
+use itertools::Itertools;
+use nom::{
+    character::complete::{char, digit1, multispace0, space0},
+    combinator::{cut, map_res},
+    multi::many1,
+    sequence::{preceded, separated_pair},
+};
+
+use crate::{
+    cancel,
+    error::{self, IResult},
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+trait Combos {
+    fn combos(a: i64, b: i64) -> impl Iterator<Item = i64>;
+}
+
+impl Combos for Problem<'_, true> {
+    fn combos(a: i64, b: i64) -> impl Iterator<Item = i64> {
+        [
+            a * b,
+            a + b,
+            (a.to_string() + &b.to_string()).parse().unwrap(),
+        ]
+        .into_iter()
+    }
+}
+
+impl Combos for Problem<'_, false> {
+    fn combos(a: i64, b: i64) -> impl Iterator<Item = i64> {
+        [a * b, a + b].into_iter()
+    }
+}
+
+struct Problem<'a, const P2: bool> {
+    target: i64,
+    nums: &'a [i64],
+}
+
+impl<const P2: bool> Problem<'_, P2>
+where
+    Self: Combos,
+{
+    fn solveable(&self, a: i64, i: usize) -> bool {
+        if a > self.target {
+            return false;
+        }
+        let Some(&b) = self.nums.get(i) else {
+            return a == self.target;
+        };
+        Self::combos(a, b).any(|c| self.solveable(c, i + 1))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        fn num(input: &str) -> IResult<&str, i64> {
+            map_res(digit1, str::parse)(input)
+        }
+        let eq = separated_pair(num, cut(char(':')), cut(many1(preceded(space0, num))));
+        error::parse(input, many1(preceded(multispace0, eq)))
+    }
+
+    /// Generates `size` equations, most of which can be made true with
+    /// some of the operators.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut lines = Vec::new();
+        while lines.len() < size.max(1) {
+            let nums = (0..rng.range(2..10))
+                .map(|_| rng.range(1..1000))
+                .collect_vec();
+            let ops: &[fn(i64, i64) -> Option<i64>] =
+                &[i64::checked_add, i64::checked_mul, |a, b| {
+                    format!("{a}{b}").parse().ok()
+                }];
+            let target = nums[1..]
+                .iter()
+                .try_fold(nums[0], |a, &b| rng.choose(ops)(a, b));
+            // Keep the targets small enough that concatenating can't overflow.
+            let Some(target) = target.filter(|&target| target < 1_000_000_000_000_000) else {
+                continue;
+            };
+            let target = target + rng.chance(0.3) as i64;
+            lines.push(format!("{target}: {}\n", nums.iter().join(" ")));
+        }
+        Some(Generated::new(lines.concat()))
+    }
+
+    fn part1(eqs: &Self::Parsed<'_>) -> i64 {
+        eqs.iter()
+            .map(|(target, nums)| Problem::<false> {
+                target: *target,
+                nums,
+            })
+            .filter(|problem| problem.solveable(0, 0))
+            .map(|problem| problem.target)
+            .sum()
+    }
+
+    fn part2(eqs: &Self::Parsed<'_>) -> i64 {
+        eqs.iter()
+            .map(|(target, nums)| Problem::<true> {
+                target: *target,
+                nums,
+            })
+            .inspect(|_| cancel::check())
+            .filter(|problem| problem.solveable(0, 0))
+            .map(|problem| problem.target)
+            .sum()
+    }
+}
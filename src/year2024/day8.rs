@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+fn gcd(a: i32, b: i32) -> i32 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+    while b != 0 {
+        (a, b) = (b, a.rem_euclid(b));
+    }
+    a
+}
+
+fn compute<Gen, Antinodes, Steps>(map: &HashMap<char, Vec<(i32, i32)>>, gen_antinodes: Gen) -> usize
+where
+    Gen: Copy + Fn(((i32, i32), (i32, i32))) -> Antinodes,
+    Antinodes: 'static + IntoIterator<Item = ((i32, i32), (i32, i32), Steps)>,
+    Steps: IntoIterator<Item = i32>,
+{
+    let &(max_y, max_x) = map.values().flatten().max().unwrap();
+    let in_bounds = move |(y, x): &(i32, i32)| (0..=max_y).contains(y) && (0..=max_x).contains(x);
+    map.iter()
+        .filter(|&(&c, _)| c != '.')
+        .flat_map(move |(_, coords)| {
+            coords
+                .iter()
+                .copied()
+                .tuple_combinations()
+                .flat_map(gen_antinodes)
+        })
+        .flat_map(|((y, x), (dy, dx), range)| {
+            range
+                .into_iter()
+                .map(move |i| (y + i * dy, x + i * dx))
+                .take_while(in_bounds)
+        })
+        .unique()
+        .count()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = HashMap<char, Vec<(i32, i32)>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        let mut map: HashMap<char, Vec<(i32, i32)>> = HashMap::new();
+        for (y, line) in input.trim().lines().enumerate() {
+            for (x, c) in line.trim().chars().enumerate() {
+                map.entry(c).or_default().push((y as i32, x as i32));
+            }
+        }
+        if map.is_empty() {
+            return Err(Error::missing(input, "a map of antennas"));
+        }
+        Ok(map)
+    }
+
+    /// Generates a map `size` tiles square, with a few antennas of each
+    /// frequency.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let size = size.max(1);
+        let freqs = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect_vec();
+        let freqs = &freqs[..(size / 2).clamp(1, freqs.len())];
+        let rows = (0..size).map(|_| {
+            let row = (0..size).map(|_| match rng.chance(0.08) {
+                true => *rng.choose(freqs),
+                false => '.',
+            });
+            row.chain(['\n']).collect::<String>()
+        });
+        Some(Generated::new(rows.collect()))
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> usize {
+        compute(map, |((y1, x1), (y2, x2))| {
+            let (dy, dx) = (y2 - y1, x2 - x1);
+            [((y1, x1), (dy, dx), [-1]), ((y2, x2), (dy, dx), [1])]
+        })
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> usize {
+        compute(map, |((y1, x1), (y2, x2))| {
+            let (dy, dx) = (y2 - y1, x2 - x1);
+            let div = gcd(dy, dx);
+            [
+                ((y1, x1), (dy / div, dx / div), 0..),
+                ((y1, x1), (-dy / div, -dx / div), 1..),
+            ]
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::{cmp::Reverse, collections::BinaryHeap, mem::take};
+
+use crate::{
+    rng::Rng,
+    solution::{Generated, Solution},
+    Error,
+};
+
+#[derive(Clone)]
+struct File {
+    len: usize,
+    val: usize,
+}
+
+#[derive(Clone)]
+pub struct FS {
+    files: Vec<(usize, File)>,
+    empty_pos: [BinaryHeap<Reverse<usize>>; 10],
+}
+
+impl FS {
+    fn new(input: &str) -> Result<Self, Error> {
+        let mut files = Vec::new();
+        let mut empty_pos: [BinaryHeap<Reverse<usize>>; 10] = Default::default();
+        let mut pos = 0;
+        let disk_map = input.trim();
+        for (i, (j, c)) in disk_map.char_indices().enumerate() {
+            let Some(len) = c.to_digit(10) else {
+                return Err(Error::at(input, &disk_map[j..], "a digit"));
+            };
+            let len = len as usize;
+            if i % 2 == 0 {
+                files.push((pos, File { len, val: i / 2 }));
+            } else {
+                empty_pos[len].push(Reverse(pos));
+            }
+            pos += len;
+        }
+        Ok(Self { files, empty_pos })
+    }
+
+    fn split_files(mut self) -> Self {
+        for (pos, file) in take(&mut self.files).into_iter() {
+            for i in 0..file.len {
+                self.files.push((pos + i, File { len: 1, ..file }));
+            }
+        }
+        self
+    }
+
+    fn compact(mut self) -> Vec<(usize, File)> {
+        for (mut pos, file) in take(&mut self.files).into_iter().rev() {
+            let first_space = (file.len..self.empty_pos.len())
+                .filter(|&i| !self.empty_pos[i].is_empty())
+                .filter(|&i| self.empty_pos[i].peek().unwrap().0 < pos)
+                .min_by_key(|&i| self.empty_pos[i].peek().unwrap().0);
+            if let Some(space) = first_space {
+                pos = self.empty_pos[space].pop().unwrap().0;
+                if space > file.len {
+                    self.empty_pos[space - file.len].push(Reverse(pos + file.len));
+                }
+            };
+            self.files.push((pos, file))
+        }
+        self.files.sort_by_key(|&(pos, _)| pos);
+        self.files
+    }
+}
+
+fn checksum(files: impl IntoIterator<Item = (usize, File)>) -> usize {
+    files
+        .into_iter()
+        .map(|(pos, File { len, val })| val * len * (2 * pos + len - 1) / 2)
+        .sum()
+}
+
+/// The disk a block at a time, with the ID of the file in each block.
+fn blocks(fs: &FS) -> Vec<Option<usize>> {
+    let mut blocks = Vec::new();
+    for (pos, file) in &fs.files {
+        blocks.resize(*pos, None);
+        blocks.resize(pos + file.len, Some(file.val));
+    }
+    blocks
+}
+
+fn block_checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(pos, val)| pos * val.unwrap_or(0))
+        .sum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = FS;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error> {
+        FS::new(input)
+    }
+
+    /// Generates a disk map of `size` digits.
+    fn generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+        let digits = (0..size.max(1)).map(|i| match i % 2 {
+            0 => rng.range(1..10),
+            _ => rng.range(0..10),
+        });
+        Some(Generated::new(
+            digits.map(|d| d.to_string()).collect::<String>() + "\n",
+        ))
+    }
+
+    fn part1(fs: &Self::Parsed<'_>) -> usize {
+        checksum(fs.clone().split_files().compact())
+    }
+
+    fn part2(fs: &Self::Parsed<'_>) -> usize {
+        checksum(fs.clone().compact())
+    }
+
+    /// Moves the last block into the first free one until there is none
+    /// left of it.
+    fn reference1(fs: &Self::Parsed<'_>) -> Option<usize> {
+        let mut blocks = blocks(fs);
+        loop {
+            let free = blocks.iter().position(Option::is_none);
+            let last = blocks.iter().rposition(Option::is_some);
+            match (free, last) {
+                (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+                _ => return Some(block_checksum(&blocks)),
+            }
+        }
+    }
+
+    /// Moves each file in turn, from the last, into the first run of free
+    /// blocks left of it that is long enough.
+    fn reference2(fs: &Self::Parsed<'_>) -> Option<usize> {
+        let mut blocks = blocks(fs);
+        for file in fs.files.iter().rev().map(|(_, file)| file) {
+            let Some(pos) = blocks.iter().position(|&val| val == Some(file.val)) else {
+                continue;
+            };
+            let free = (0..pos).find(|&i| blocks[i..i + file.len].iter().all(Option::is_none));
+            if let Some(free) = free {
+                blocks[pos..pos + file.len].fill(None);
+                blocks[free..free + file.len].fill(Some(file.val));
+            }
+        }
+        Some(block_checksum(&blocks))
+    }
+}